@@ -0,0 +1,307 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{ParseError, Point};
+
+/// A rectangular grid stored row by row. Cells are addressed by `Point`, with
+/// `x` as the column and `y` as the row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Returned when rows handed to a `Grid` are not all the same length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RaggedGridError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for RaggedGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {} has {} cells but the first row has {}",
+            self.row, self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for RaggedGridError {}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedGridError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            if cells_in_row.len() != width {
+                return Err(RaggedGridError {
+                    row,
+                    expected: width,
+                    found: cells_in_row.len(),
+                });
+            }
+            cells.extend(cells_in_row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Builds a grid from one line of input per row and one char per cell.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, RaggedGridError> {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut cell).collect())
+                .collect(),
+        )
+    }
+
+    /// Like `parse`, but lets the cell conversion fail with the caller's error,
+    /// reported at the offending cell.
+    pub fn try_parse<E: From<RaggedGridError>>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError<E>> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.char_indices()
+                    .map(|(offset, c)| {
+                        cell(c).map_err(|kind| {
+                            ParseError::at(kind, line, &line[offset..offset + c.len_utf8()])
+                                .offset_lines(row)
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<T>>, _>>()?;
+        Self::from_rows(rows).map_err(|error| {
+            let line = input.lines().nth(error.row).unwrap_or_default();
+            let extra = line
+                .char_indices()
+                .nth(error.expected)
+                .map_or("", |(offset, _)| &line[offset..]);
+            let mut located = ParseError::at(E::from(error), line, extra).offset_lines(error.row);
+            located.column = error.expected.min(error.found) + 1;
+            located
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Maps any point back onto the grid as if it were tiled infinitely.
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(
+            point.x.rem_euclid(self.width as i64),
+            point.y.rem_euclid(self.height as i64),
+        )
+    }
+
+    /// The orthogonal neighbours of `point` that lie inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours4()
+            .into_iter()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours8()
+            .into_iter()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on zero, and a zero-width grid has no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Every point in the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i64, y as i64)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, in row-major order, whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Draws one line per row using `cell` to pick each character.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    fn sample() -> Grid<u32> {
+        Grid::parse("123\n456", |c| c.to_digit(10).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = sample();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(0, 0)], 1);
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let grid = sample();
+
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&5));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let error = Grid::parse("123\n45", |c| c).unwrap_err();
+
+        assert_eq!(
+            error,
+            RaggedGridError {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = sample();
+
+        let corner: Vec<Point> = grid.neighbours4(Point::ORIGIN).collect();
+        let middle = grid.neighbours8(Point::new(1, 0)).count();
+
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(middle, 5);
+    }
+
+    #[test]
+    fn render_round_trips() {
+        let grid = sample();
+
+        let rendered = grid.render(|&cell| char::from_digit(cell, 10).unwrap());
+
+        assert_eq!(rendered, "123\n456");
+    }
+
+    #[test]
+    fn try_parse_locates_errors() {
+        let bad_cell = Grid::try_parse("123\n4x6", |c| {
+            c.to_digit(10).ok_or(RaggedGridError {
+                row: 0,
+                expected: 0,
+                found: 0,
+            })
+        })
+        .unwrap_err();
+        let ragged = Grid::try_parse("123\n4567", Ok::<_, RaggedGridError>).unwrap_err();
+
+        assert_eq!(
+            (bad_cell.line, bad_cell.column, bad_cell.text.as_str()),
+            (2, 2, "x")
+        );
+        assert_eq!(
+            (ragged.line, ragged.column, ragged.text.as_str()),
+            (2, 4, "7")
+        );
+    }
+
+    #[test]
+    fn wrap_and_position() {
+        let grid = sample();
+
+        assert_eq!(grid.wrap(Point::new(-1, 3)), Point::new(2, 1));
+        assert_eq!(grid.position(|&cell| cell == 5), Some(Point::new(1, 1)));
+    }
+}
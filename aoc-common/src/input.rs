@@ -0,0 +1,141 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Where a day's puzzle input should be read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/dayNN/prod.txt` in the workspace.
+    Default,
+    /// Standard input, requested on the command line with `-`.
+    Stdin,
+    File(PathBuf),
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(f, "no input file at {}", path.display()),
+            InputError::Unreadable { path, .. } => {
+                write!(f, "could not read input {}", path.display())
+            }
+            InputError::Stdin(_) => write!(f, "could not read input from stdin"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Unreadable { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+/// The directory holding every day's inputs, found relative to the workspace
+/// rather than the current directory so the runner works from anywhere.
+pub fn inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-common lives inside the workspace")
+        .join("inputs")
+}
+
+/// The path of a named input for `day`, e.g. `input_path(6, "dev.txt")`.
+pub fn input_path(day: u8, file_name: &str) -> PathBuf {
+    inputs_dir().join(format!("day{day:02}")).join(file_name)
+}
+
+pub fn default_input_path(day: u8) -> PathBuf {
+    input_path(day, "prod.txt")
+}
+
+pub fn load_input(day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Default => read_file(&default_input_path(day)),
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+            Ok(input)
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| {
+        if source.kind() == io::ErrorKind::NotFound {
+            InputError::Missing {
+                path: path.to_path_buf(),
+            }
+        } else {
+            InputError::Unreadable {
+                path: path.to_path_buf(),
+                source,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn parse_source() {
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(
+            "some/file.txt".parse::<InputSource>().unwrap(),
+            InputSource::File(PathBuf::from("some/file.txt"))
+        );
+    }
+
+    #[test]
+    fn default_paths_are_zero_padded() {
+        let path = default_input_path(3);
+
+        assert!(path.ends_with("inputs/day03/prod.txt"));
+    }
+
+    #[test]
+    fn load_default() {
+        let input = load_input(1, &InputSource::Default).unwrap();
+
+        assert!(!input.is_empty());
+    }
+
+    #[test]
+    fn missing_file_is_reported() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+
+        let error = load_input(1, &source).unwrap_err();
+
+        assert!(matches!(error, InputError::Missing { .. }));
+        assert_eq!(error.to_string(), "no input file at does/not/exist.txt");
+    }
+}
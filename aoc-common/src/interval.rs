@@ -0,0 +1,259 @@
+/// A non-empty run of integers from `start` to `end`, both included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    /// Returns `None` if `end` comes before `start`.
+    pub fn new(start: i64, end: i64) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    /// How many integers the interval holds, which is never 0. This is an
+    /// `i128` as the widest intervals hold more integers than an `i64` can
+    /// count.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> i128 {
+        i128::from(self.end) - i128::from(self.start) + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether all of `other` lies within this interval.
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the intervals share at least one integer.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// How many integers the intervals share.
+    pub fn overlap_len(&self, other: &Interval) -> i128 {
+        self.intersection(other).map_or(0, |shared| shared.len())
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Both intervals as one, if they overlap or touch end to end.
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        (self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1))
+            .then(|| Interval {
+                start: self.start.min(other.start),
+                end: self.end.max(other.end),
+            })
+    }
+
+    /// The integers in this interval but not in `other`, which may be split
+    /// in two.
+    pub fn difference(&self, other: &Interval) -> IntervalSet {
+        let before = Interval::new(self.start, self.end.min(other.start.saturating_sub(1)));
+        let after = Interval::new(self.start.max(other.end.saturating_add(1)), self.end);
+        before.into_iter().chain(after).collect()
+    }
+}
+
+/// A set of integers stored as sorted intervals, merged so that no two of
+/// them overlap or touch.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// The merged intervals, in order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> i128 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, mut interval: Interval) {
+        // Everything from `first` up to `last` touches the new interval, so
+        // it all merges into one.
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end.saturating_add(1));
+        for existing in &self.intervals[first..last] {
+            interval = interval.union(existing).expect("touching intervals merge");
+        }
+        self.intervals.splice(first..last, [interval]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut shared = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            shared.extend(a.intersection(b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals: shared }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut remaining = self.clone();
+        for interval in &other.intervals {
+            remaining = remaining
+                .intervals
+                .iter()
+                .flat_map(|kept| kept.difference(interval).intervals)
+                .collect();
+        }
+        remaining
+    }
+
+    /// The integers within `bounds` that the set does not hold.
+    pub fn gaps(&self, bounds: Interval) -> IntervalSet {
+        IntervalSet::from_iter([bounds]).difference(self)
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted: Vec<Interval> = iter.into_iter().collect();
+        sorted.sort_unstable();
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if last.union(&interval).is_some() => {
+                    last.end = last.end.max(interval.end);
+                }
+                _ => intervals.push(interval),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    fn interval(start: i64, end: i64) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| interval(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn interval_algebra() {
+        let a = interval(2, 6);
+        let b = interval(4, 8);
+
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(a.len(), 5);
+        assert_eq!(a.intersection(&b), Some(interval(4, 6)));
+        assert_eq!(a.overlap_len(&b), 3);
+        assert_eq!(a.union(&b), Some(interval(2, 8)));
+        assert_eq!(a.union(&interval(7, 9)), Some(interval(2, 9)));
+        assert_eq!(a.union(&interval(8, 9)), None);
+        assert_eq!(a.difference(&b), set(&[(2, 3)]));
+        assert_eq!(a.difference(&interval(3, 4)), set(&[(2, 2), (5, 6)]));
+        assert!(a.difference(&interval(0, 10)).is_empty());
+        assert!(interval(0, 10).contains_interval(&a));
+        assert!(!a.contains_interval(&b));
+        assert!(!a.overlaps(&interval(7, 7)));
+    }
+
+    #[test]
+    fn full_width_lengths_do_not_overflow() {
+        let full = interval(i64::MIN, i64::MAX);
+
+        assert_eq!(full.len(), 1 << 64);
+        assert_eq!(interval(0, i64::MAX).len(), 1 << 63);
+        assert_eq!(full.overlap_len(&full), 1 << 64);
+        assert_eq!(set(&[(i64::MIN, -1), (0, i64::MAX)]).len(), 1 << 64);
+    }
+
+    #[test]
+    fn sets_merge_touching_intervals() {
+        let merged = set(&[(10, 12), (1, 3), (4, 5), (2, 2), (8, 8)]);
+
+        assert_eq!(
+            merged.intervals(),
+            set(&[(1, 5), (8, 8), (10, 12)]).intervals()
+        );
+        assert_eq!(merged.len(), 9);
+        assert!(merged.contains(5) && !merged.contains(6) && merged.contains(11));
+
+        let mut inserted = IntervalSet::new();
+        inserted.extend([(10, 12), (1, 3), (4, 5), (2, 2), (8, 8)].map(|(s, e)| interval(s, e)));
+        assert_eq!(inserted, merged);
+        inserted.insert(interval(6, 9));
+        assert_eq!(inserted, set(&[(1, 12)]));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(0, 4), (10, 14)]);
+        let b = set(&[(3, 11), (20, 20)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 14), (20, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 4), (10, 11)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (12, 14)]));
+        assert_eq!(a.gaps(interval(-2, 16)), set(&[(-2, -1), (5, 9), (15, 16)]));
+    }
+}
@@ -0,0 +1,15 @@
+mod direction;
+mod grid;
+mod input;
+mod interval;
+mod parse;
+mod point;
+mod solution;
+
+pub use direction::Direction;
+pub use grid::{Grid, RaggedGridError};
+pub use input::{default_input_path, input_path, inputs_dir, load_input, InputError, InputSource};
+pub use interval::{Interval, IntervalSet};
+pub use parse::{parse_lines, Diagnostic, ParseError};
+pub use point::{Point, Point3};
+pub use solution::{Part, Solution};
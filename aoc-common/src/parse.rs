@@ -0,0 +1,213 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A failure to parse puzzle input, pinned to the text that caused it. `line`
+/// and `column` count from 1, and `kind` is the day's own error enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError<K> {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub kind: K,
+}
+
+impl<K> ParseError<K> {
+    pub fn new(kind: K, line: usize, column: usize, text: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            kind,
+        }
+    }
+
+    /// Points at `text` inside `source`, working out the line and column from
+    /// where the slice sits. Text that is not a slice of `source` is reported
+    /// at the start of it.
+    pub fn at(kind: K, source: &str, text: &str) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|offset| offset + text.len() <= source.len())
+            .unwrap_or(0);
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        ParseError::new(
+            kind,
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            text,
+        )
+    }
+
+    /// Moves an error found in a slice of the input down by the number of
+    /// lines that came before that slice.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Moves an error found while parsing `slice` on its own to the matching
+    /// place in `source`, of which `slice` must be a part.
+    pub fn within(mut self, source: &str, slice: &str) -> Self {
+        let start = ParseError::at((), source, slice);
+        if self.line == 1 {
+            self.column += start.column - 1;
+        }
+        self.line += start.line - 1;
+        self
+    }
+
+    pub fn map_kind<L>(self, f: impl FnOnce(K) -> L) -> ParseError<L> {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            text: self.text,
+            kind: f(self.kind),
+        }
+    }
+}
+
+impl<K: fmt::Display> fmt::Display for ParseError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " ({:?})", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: fmt::Display + fmt::Debug> std::error::Error for ParseError<K> {}
+
+/// Parses every line of `input`, fixing up the line numbers of any error.
+pub fn parse_lines<T, K>(input: &str) -> Result<Vec<T>, ParseError<K>>
+where
+    T: FromStr<Err = ParseError<K>>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|error: ParseError<K>| error.offset_lines(index))
+        })
+        .collect()
+}
+
+/// A `ParseError` with the day-specific kind turned into a message, so errors
+/// from every day can be reported the same way.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Renders the error the way a compiler would, quoting the offending line
+    /// of `input` and underlining the text at fault.
+    pub fn render(&self, source_name: &str, input: &str) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let mut rendered = format!(
+            "error: {}\n{gutter}--> {source_name}:{}:{}\n",
+            self.message, self.line, self.column
+        );
+        if let Some(source_line) = input.lines().nth(self.line - 1) {
+            let indent = " ".repeat(self.column - 1);
+            let underline = "^".repeat(self.text.chars().count().max(1));
+            rendered.push_str(&format!(
+                "{gutter} |\n{} | {source_line}\n{gutter} | {indent}{underline}\n",
+                self.line
+            ));
+        }
+        rendered
+    }
+}
+
+impl<K: fmt::Display> From<ParseError<K>> for Diagnostic {
+    fn from(error: ParseError<K>) -> Self {
+        Diagnostic {
+            line: error.line,
+            column: error.column,
+            message: error.kind.to_string(),
+            text: error.text,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq)]
+    struct Number(u32);
+
+    impl FromStr for Number {
+        type Err = ParseError<&'static str>;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let digits = s.trim();
+            digits
+                .parse()
+                .map(Number)
+                .map_err(|_| ParseError::at("not a number", s, digits))
+        }
+    }
+
+    #[test]
+    fn at_finds_line_and_column() {
+        let source = "first\nsecond line\nthird";
+        let text = &source[13..17];
+
+        let error = ParseError::at("bad", source, text);
+
+        assert_eq!(error, ParseError::new("bad", 2, 8, "line"));
+    }
+
+    #[test]
+    fn within_moves_errors_into_the_source() {
+        let source = "header\nmove 1\nmove x";
+        let block = &source[9..];
+
+        let error = ParseError::at("bad", block, &block[10..11]).within(source, block);
+
+        assert_eq!(error, ParseError::new("bad", 3, 6, "x"));
+    }
+
+    #[test]
+    fn parse_lines_reports_the_failing_line() {
+        let error = parse_lines::<Number, _>("1\n2\n  x3\n4").unwrap_err();
+
+        assert_eq!(error, ParseError::new("not a number", 3, 3, "x3"));
+    }
+
+    #[test]
+    fn render_underlines_the_text() {
+        let diagnostic = Diagnostic::from(ParseError::new("not a number", 2, 6, "ten"));
+
+        let rendered = diagnostic.render("input.txt", "move 1\nmove ten");
+
+        assert_eq!(
+            rendered,
+            "error: not a number\n --> input.txt:2:6\n  |\n2 | move ten\n  |      ^^^\n"
+        );
+    }
+}
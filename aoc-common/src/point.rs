@@ -0,0 +1,186 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Direction;
+
+/// A signed 2D point. `y` grows downwards so that points line up with the
+/// row/column layout of puzzle input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+
+    pub fn step_by(self, direction: Direction, distance: i64) -> Point {
+        self + direction.delta() * distance
+    }
+
+    /// The four orthogonally adjacent points, in `Direction::ALL` order.
+    pub fn neighbours4(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// All eight adjacent points, clockwise starting from the top left.
+    pub fn neighbours8(self) -> [Point; 8] {
+        [
+            Point::new(-1, -1),
+            Point::new(0, -1),
+            Point::new(1, -1),
+            Point::new(1, 0),
+            Point::new(1, 1),
+            Point::new(0, 1),
+            Point::new(-1, 1),
+            Point::new(-1, 0),
+        ]
+        .map(|delta| self + delta)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i64) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan_distance(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> [Point3; 6] {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .map(|delta| self + delta)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let point = Point::new(2, -3);
+
+        assert_eq!(point + Point::new(1, 1), Point::new(3, -2));
+        assert_eq!(point - Point::new(1, 1), Point::new(1, -4));
+        assert_eq!(point * 3, Point::new(6, -9));
+        assert_eq!(-point, Point::new(-2, 3));
+        assert_eq!(point.step_by(Direction::Up, 2), Point::new(2, -5));
+    }
+
+    #[test]
+    fn manhattan_distance() {
+        assert_eq!(Point::new(8, 7).manhattan_distance(Point::new(2, 10)), 9);
+        assert_eq!(Point3::ORIGIN.manhattan_distance(Point3::new(1, -2, 3)), 6);
+    }
+
+    #[test]
+    fn neighbours() {
+        let point = Point::new(5, 5);
+
+        assert_eq!(
+            point.neighbours4(),
+            [
+                Point::new(5, 4),
+                Point::new(6, 5),
+                Point::new(5, 6),
+                Point::new(4, 5)
+            ]
+        );
+        assert_eq!(point.neighbours8().len(), 8);
+        assert!(!point.neighbours8().contains(&point));
+        assert!(Point3::ORIGIN
+            .neighbours6()
+            .iter()
+            .all(|neighbour| neighbour.manhattan_distance(Point3::ORIGIN) == 1));
+    }
+}
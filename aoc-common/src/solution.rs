@@ -0,0 +1,79 @@
+use anyhow::{anyhow, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// One day's puzzle. Each day crate exposes a unit struct implementing this so
+/// the runner can dispatch to it without knowing anything about the day.
+pub trait Solution {
+    fn part_1(&self, input: &str) -> Result<String>;
+    fn part_2(&self, input: &str) -> Result<String>;
+
+    fn solve(&self, part: Part, input: &str) -> Result<String> {
+        match part {
+            Part::One => self.part_1(input),
+            Part::Two => self.part_2(input),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(anyhow!("part must be 1 or 2, got {other:?}")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        fn part_1(&self, input: &str) -> anyhow::Result<String> {
+            Ok(format!("one:{input}"))
+        }
+        fn part_2(&self, input: &str) -> anyhow::Result<String> {
+            Ok(format!("two:{input}"))
+        }
+    }
+
+    #[test]
+    fn solve_dispatches_on_part() {
+        let solution = Echo;
+
+        assert_eq!(solution.solve(Part::One, "x").unwrap(), "one:x");
+        assert_eq!(solution.solve(Part::Two, "x").unwrap(), "two:x");
+    }
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
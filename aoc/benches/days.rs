@@ -0,0 +1,44 @@
+//! Benchmarks both parts of every day against its dev and prod inputs.
+//!
+//! Some days take seconds per run, so narrow the run with a filter while
+//! working on one of them, e.g. `cargo bench -p aoc --bench days -- day07/`.
+//! `aoc bench-report` turns the results into a markdown table.
+
+use std::fs;
+
+use aoc::{solution, FIRST_DAY, LAST_DAY};
+use aoc_common::{input_path, Part};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, SamplingMode};
+
+const INPUTS: [&str; 2] = ["dev", "prod"];
+
+/// Parts that only return an error, so timing them would say nothing.
+const UNSOLVED: [(u8, Part); 3] = [(17, Part::Two), (22, Part::Two), (25, Part::Two)];
+
+fn bench_days(c: &mut Criterion) {
+    for day in FIRST_DAY..=LAST_DAY {
+        let solution = solution(day).expect("every day is registered");
+        let mut group = c.benchmark_group(format!("day{day:02}"));
+        group.sample_size(10).sampling_mode(SamplingMode::Flat);
+        for name in INPUTS {
+            // Not every day has both inputs checked in.
+            let Ok(input) = fs::read_to_string(input_path(day, &format!("{name}.txt"))) else {
+                continue;
+            };
+            for part in Part::ALL {
+                if UNSOLVED.contains(&(day, part)) {
+                    continue;
+                }
+                group.bench_with_input(
+                    BenchmarkId::new(format!("part{part}"), name),
+                    input.as_str(),
+                    |b, input| b.iter(|| solution.solve(part, input)),
+                );
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
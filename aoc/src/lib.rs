@@ -0,0 +1,56 @@
+use aoc_common::Solution;
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 25;
+
+const SOLUTIONS: [&(dyn Solution + Sync); 25] = [
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+/// Looks up the solution for `day`, which is 1-based like the puzzles.
+pub fn solution(day: u8) -> Option<&'static (dyn Solution + Sync)> {
+    let index = usize::from(day.checked_sub(FIRST_DAY)?);
+    SOLUTIONS.get(index).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn every_day_is_registered() {
+        for day in FIRST_DAY..=LAST_DAY {
+            assert!(solution(day).is_some(), "day {day} is missing");
+        }
+    }
+
+    #[test]
+    fn out_of_range_days_are_rejected() {
+        assert!(solution(0).is_none());
+        assert!(solution(26).is_none());
+    }
+}
@@ -0,0 +1,194 @@
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use anyhow::{anyhow, Context, Error, Result};
+use aoc_common::{default_input_path, load_input, Diagnostic, InputSource, Part};
+use clap::{Parser, Subcommand};
+use tracing::info_span;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+mod report;
+mod tools;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log solver progress to stderr at this level: off, error, warn, info,
+    /// debug or trace. Nothing is logged by default.
+    #[arg(long, global = true)]
+    log_level: Option<LevelFilter>,
+    /// Log as JSON lines, at info level unless `--log-level` says otherwise.
+    #[arg(long, global = true)]
+    trace_json: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day against an input file.
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(aoc::FIRST_DAY as i64..=aoc::LAST_DAY as i64))]
+        day: u8,
+        /// Which part to run. Both parts are run when omitted.
+        #[arg(long)]
+        part: Option<Part>,
+        /// Input file, or `-` for stdin. Defaults to `inputs/dayNN/prod.txt`.
+        #[arg(long)]
+        input: Option<InputSource>,
+    },
+    /// Write a markdown table of median times from `cargo bench -p aoc`.
+    BenchReport {
+        /// Criterion's output directory. Defaults to `target/criterion`.
+        #[arg(long)]
+        criterion_dir: Option<PathBuf>,
+        /// File to write the table to instead of stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Rock paper scissors planning and strategy tournaments for day 2.
+    Day2 {
+        #[command(subcommand)]
+        command: tools::day2::Command,
+    },
+    /// Rucksack reports for day 3.
+    Day3 {
+        #[command(subcommand)]
+        command: tools::day3::Command,
+    },
+    /// Section assignment reports for day 4.
+    Day4 {
+        #[command(subcommand)]
+        command: tools::day4::Command,
+    },
+    /// Crate stack drawings for day 5.
+    Day5 {
+        #[command(subcommand)]
+        command: tools::day5::Command,
+    },
+    /// Disk usage views of the day 7 file system.
+    Day7 {
+        #[command(subcommand)]
+        command: tools::day7::Command,
+    },
+    /// Forest heatmaps and images for day 8.
+    Day8 {
+        #[command(subcommand)]
+        command: tools::day8::Command,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_tracing(cli.log_level, cli.trace_json);
+    let result = match cli.command {
+        Command::Run { day, part, input } => run(day, part, input.unwrap_or(InputSource::Default)),
+        Command::BenchReport {
+            criterion_dir,
+            output,
+        } => bench_report(criterion_dir, output),
+        Command::Day2 { command } => tools::day2::run(command),
+        Command::Day3 { command } => tools::day3::run(command),
+        Command::Day4 { command } => tools::day4::run(command),
+        Command::Day5 { command } => tools::day5::run(command),
+        Command::Day7 { command } => tools::day7::run(command),
+        Command::Day8 { command } => tools::day8::run(command),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Sends solver logs to stderr, keeping stdout for answers. Each part runs in
+/// its own span, which is logged with its timing when it closes.
+fn init_tracing(log_level: Option<LevelFilter>, json: bool) {
+    let default_level = if json {
+        LevelFilter::INFO
+    } else {
+        LevelFilter::OFF
+    };
+    let level = log_level.unwrap_or(default_level);
+    if level == LevelFilter::OFF {
+        return;
+    }
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(io::stderr().is_terminal())
+        .with_writer(io::stderr);
+    if json {
+        subscriber.json().init();
+    } else {
+        subscriber.init();
+    }
+}
+
+fn run(day: u8, part: Option<Part>, source: InputSource) -> Result<()> {
+    let solution = aoc::solution(day).ok_or_else(|| anyhow!("day {day} has no solution"))?;
+    let input = load_input(day, &source)?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    for part in parts {
+        let _span = info_span!("solve", day, %part).entered();
+        let start = Instant::now();
+        let answer = solution
+            .solve(part, &input)
+            .map_err(|error| report(error, day, part, &source, &input))?;
+        let elapsed = start.elapsed();
+        if answer.contains('\n') {
+            println!("Day {day} part {part} ({elapsed:.2?}):\n{answer}");
+        } else {
+            println!("Day {day} part {part}: {answer} ({elapsed:.2?})");
+        }
+    }
+    Ok(())
+}
+
+fn bench_report(criterion_dir: Option<PathBuf>, output: Option<PathBuf>) -> Result<()> {
+    let dir = criterion_dir.unwrap_or_else(report::default_criterion_dir);
+    let measurements = report::read_measurements(&dir)?;
+    if measurements.is_empty() {
+        return Err(anyhow!(
+            "no benchmark results in {}, run `cargo bench -p aoc` first",
+            dir.display()
+        ));
+    }
+    let table = report::render_table(&measurements);
+    match output {
+        Some(path) => fs::write(&path, table)
+            .with_context(|| format!("could not write {}", path.display()))?,
+        None => print!("{table}"),
+    }
+    Ok(())
+}
+
+/// Prints parse errors against the input they came from, then hands back an
+/// error saying which part failed.
+fn report(error: Error, day: u8, part: Part, source: &InputSource, input: &str) -> Error {
+    match error.downcast_ref::<Diagnostic>() {
+        Some(diagnostic) => {
+            eprint!("{}", diagnostic.render(&source_name(day, source), input));
+            anyhow!("day {day} part {part} could not parse its input")
+        }
+        None => error.context(format!("day {day} part {part} failed")),
+    }
+}
+
+fn source_name(day: u8, source: &InputSource) -> String {
+    match source {
+        InputSource::Default => default_input_path(day).display().to_string(),
+        InputSource::Stdin => "<stdin>".to_string(),
+        InputSource::File(path) => path.display().to_string(),
+    }
+}
@@ -0,0 +1,169 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+
+/// The median time of one benchmark from `benches/days.rs`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub median: Duration,
+}
+
+/// Where criterion leaves its results, honouring `CARGO_TARGET_DIR`.
+pub fn default_criterion_dir() -> PathBuf {
+    let target = env::var_os("CARGO_TARGET_DIR").map_or_else(
+        || {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .expect("aoc lives inside the workspace")
+                .join("target")
+        },
+        PathBuf::from,
+    );
+    target.join("criterion")
+}
+
+/// Reads every `dayNN/partN/<input>` result criterion has saved under `dir`,
+/// sorted by day, part and input.
+pub fn read_measurements(dir: &Path) -> Result<Vec<Measurement>> {
+    let mut measurements = Vec::new();
+    for (group, day) in numbered_dirs(dir, "day")? {
+        for (function, part) in numbered_dirs(&group, "part")? {
+            for input in subdirs(&function)? {
+                let Some(name) = input.file_name().and_then(|name| name.to_str()) else {
+                    continue;
+                };
+                if name == "report" {
+                    continue;
+                }
+                let estimates = input.join("new").join("estimates.json");
+                if !estimates.exists() {
+                    continue;
+                }
+                measurements.push(Measurement {
+                    day,
+                    part,
+                    input: name.to_string(),
+                    median: read_median(&estimates)?,
+                });
+            }
+        }
+    }
+    measurements.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    Ok(measurements)
+}
+
+pub fn render_table(measurements: &[Measurement]) -> String {
+    let mut table =
+        String::from("| Day | Part | Input | Median |\n|----:|-----:|:------|-------:|\n");
+    for measurement in measurements {
+        table.push_str(&format!(
+            "| {} | {} | {} | {:.2?} |\n",
+            measurement.day, measurement.part, measurement.input, measurement.median
+        ));
+    }
+    table
+}
+
+fn read_median(estimates: &Path) -> Result<Duration> {
+    let contents = fs::read_to_string(estimates)
+        .with_context(|| format!("could not read {}", estimates.display()))?;
+    let json: Value = serde_json::from_str(&contents)
+        .with_context(|| format!("{} is not valid JSON", estimates.display()))?;
+    let nanos = json["median"]["point_estimate"]
+        .as_f64()
+        .ok_or_else(|| anyhow!("{} has no median", estimates.display()))?;
+    Ok(Duration::from_secs_f64(nanos / 1e9))
+}
+
+/// Subdirectories named `<prefix><number>`, such as `day07` or `part2`.
+fn numbered_dirs(dir: &Path, prefix: &str) -> Result<Vec<(PathBuf, u8)>> {
+    Ok(subdirs(dir)?
+        .into_iter()
+        .filter_map(|path| {
+            let number = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(prefix)?
+                .parse()
+                .ok()?;
+            Some((path, number))
+        })
+        .collect())
+}
+
+fn subdirs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).with_context(|| format!("could not read {}", dir.display()))?;
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    Ok(dirs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn write_estimate(root: &Path, bench: &str, median_nanos: f64) {
+        let dir = root.join(bench).join("new");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("estimates.json"),
+            format!(r#"{{"median": {{"point_estimate": {median_nanos}}}}}"#),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn reads_medians_in_order() {
+        let root = env::temp_dir().join(format!("aoc-bench-report-{}", std::process::id()));
+        write_estimate(&root, "day10/part2/prod", 2_500_000.0);
+        write_estimate(&root, "day10/part1/prod", 1_500.0);
+        write_estimate(&root, "day02/part1/dev", 40.0);
+        fs::create_dir_all(root.join("day02/report")).unwrap();
+        fs::create_dir_all(root.join("report")).unwrap();
+
+        let measurements = read_measurements(&root).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let benches: Vec<_> = measurements
+            .iter()
+            .map(|m| (m.day, m.part, m.input.as_str(), m.median.as_nanos()))
+            .collect();
+        assert_eq!(
+            benches,
+            vec![
+                (2, 1, "dev", 40),
+                (10, 1, "prod", 1_500),
+                (10, 2, "prod", 2_500_000)
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_a_markdown_table() {
+        let measurements = vec![Measurement {
+            day: 6,
+            part: 2,
+            input: "prod".to_string(),
+            median: Duration::from_micros(980),
+        }];
+
+        let table = render_table(&measurements);
+
+        assert_eq!(
+            table,
+            "| Day | Part | Input | Median |\n|----:|-----:|:------|-------:|\n| 6 | 2 | prod | 980.00µs |\n"
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! Day-specific commands that go beyond printing answers.
+
+use anyhow::{anyhow, Error};
+use aoc_common::{load_input, Diagnostic, InputSource};
+
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day7;
+pub mod day8;
+
+/// Loads a day's input for a tool, defaulting to its prod input.
+fn input(day: u8, source: &Option<InputSource>) -> anyhow::Result<(InputSource, String)> {
+    let source = source.clone().unwrap_or(InputSource::Default);
+    let input = load_input(day, &source)?;
+    Ok((source, input))
+}
+
+/// Prints a parse error against the input it came from.
+fn parse_failed(error: impl Into<Diagnostic>, day: u8, source: &InputSource, input: &str) -> Error {
+    eprint!(
+        "{}",
+        error.into().render(&crate::source_name(day, source), input)
+    );
+    anyhow!("day {day} could not parse its input")
+}
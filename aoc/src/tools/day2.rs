@@ -0,0 +1,93 @@
+use anyhow::Result;
+use aoc_common::InputSource;
+use clap::{Args, Subcommand, ValueEnum};
+use day2::{
+    best_plan, parse_opponent_moves, tournament, Fixed, FrequencyCounter, MarkovPredictor, Move,
+    RuleSet, Strategy,
+};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Show the best response to every opponent move, knowing them all in
+    /// advance.
+    Plan(Options),
+    /// Rank strategies that only learn the opponent's moves as they are
+    /// played.
+    Tournament(Options),
+}
+
+#[derive(Args)]
+pub struct Options {
+    #[arg(long, value_enum, default_value_t = Rules::RockPaperScissors)]
+    rules: Rules,
+    /// Input file, or `-` for stdin. Defaults to `inputs/day02/prod.txt`.
+    /// Only the opponent's column is read.
+    #[arg(long)]
+    input: Option<InputSource>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Rules {
+    RockPaperScissors,
+    RockPaperScissorsLizardSpock,
+}
+
+impl Options {
+    fn load(&self) -> Result<(RuleSet, Vec<Move>)> {
+        let rules = match self.rules {
+            Rules::RockPaperScissors => RuleSet::rock_paper_scissors(),
+            Rules::RockPaperScissorsLizardSpock => RuleSet::rock_paper_scissors_lizard_spock(),
+        };
+        let (source, input) = super::input(2, &self.input)?;
+        let opponent = parse_opponent_moves(&input, &rules)
+            .map_err(|error| super::parse_failed(error, 2, &source, &input))?;
+        Ok((rules, opponent))
+    }
+}
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Plan(options) => {
+            let (rules, opponent) = options.load()?;
+            let plan = best_plan(&rules, &opponent);
+            println!(
+                "{:>5}  {:<9} {:<9} {:>5}",
+                "round", "opponent", "response", "score"
+            );
+            for (round, (&their, &our)) in opponent.iter().zip(&plan.responses).enumerate() {
+                println!(
+                    "{:>5}  {:<9} {:<9} {:>5}",
+                    round + 1,
+                    rules.name(their),
+                    rules.name(our),
+                    rules.score(our, their)
+                );
+            }
+            println!("\ntotal score {}", plan.score);
+        }
+        Command::Tournament(options) => {
+            let (rules, opponent) = options.load()?;
+            let mut strategies: Vec<Box<dyn Strategy>> = vec![
+                Box::new(FrequencyCounter::default()),
+                Box::new(MarkovPredictor::default()),
+            ];
+            strategies.extend(
+                rules
+                    .moves()
+                    .map(|m| Box::new(Fixed(m)) as Box<dyn Strategy>),
+            );
+            let results = tournament(&rules, &opponent, strategies);
+            let width = results
+                .iter()
+                .map(|(name, _)| name.len())
+                .max()
+                .unwrap_or(0);
+            for (rank, (name, record)) in results.iter().enumerate() {
+                println!("{:>2}. {name:<width$}  {record}", rank + 1);
+            }
+            let best = best_plan(&rules, &opponent);
+            println!("\nbest possible score {}", best.score);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use aoc_common::InputSource;
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Show which item and group give each priority, flagging rucksacks that
+    /// share no item or several.
+    Explain {
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+        /// How many rucksacks make a group.
+        #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u16).range(1..))]
+        group_size: u16,
+        /// Input file, or `-` for stdin. Defaults to `inputs/day03/prod.txt`.
+        #[arg(long)]
+        input: Option<InputSource>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Explain {
+            format,
+            group_size,
+            input,
+        } => {
+            let (source, input) = super::input(3, &input)?;
+            let explanation = day3::explain(&input, usize::from(group_size))
+                .map_err(|error| super::parse_failed(error, 3, &source, &input))?;
+            match format {
+                Format::Csv => print!("{}", explanation.to_csv()),
+                Format::Json => println!("{}", explanation.to_json()),
+            }
+            Ok(())
+        }
+    }
+}
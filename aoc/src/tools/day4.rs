@@ -0,0 +1,36 @@
+use anyhow::Result;
+use aoc_common::InputSource;
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Show how each pair's assignments overlap, the sections wasted and how
+    /// many elves cover each section. Lines that are not pairs are skipped.
+    Conflicts {
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Input file, or `-` for stdin. Defaults to `inputs/day04/prod.txt`.
+        #[arg(long)]
+        input: Option<InputSource>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Conflicts { format, input } => {
+            let (_, input) = super::input(4, &input)?;
+            let report = day4::conflict_report(&input);
+            match format {
+                Format::Text => print!("{}", report.to_text()),
+                Format::Json => println!("{}", report.to_json()),
+            }
+            Ok(())
+        }
+    }
+}
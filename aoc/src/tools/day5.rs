@@ -0,0 +1,136 @@
+use anyhow::{Context, Result};
+use aoc_common::InputSource;
+use clap::{Args, Subcommand, ValueEnum};
+use day5::{MaxLift, Simulation, Stacks};
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Draw the stacks in the puzzle's own diagram format.
+    Render {
+        /// How many moves to make first.
+        #[arg(long, default_value_t = 0)]
+        after: usize,
+        #[command(flatten)]
+        options: Options,
+    },
+    /// Redraw the stacks after every move.
+    Animate {
+        /// How long to show each frame, in milliseconds. Frames are printed
+        /// one after another without pausing when stdout is not a terminal.
+        #[arg(long, default_value_t = 200)]
+        delay: u64,
+        /// Also write each frame to `frame-NNNN.txt` in this directory.
+        #[arg(long)]
+        frames_dir: Option<PathBuf>,
+        #[command(flatten)]
+        options: Options,
+    },
+}
+
+#[derive(Args)]
+pub struct Options {
+    #[arg(long, value_enum, default_value_t = Model::Crane9000)]
+    crane: Model,
+    /// Lift at most this many crates at once, whatever the model.
+    #[arg(long)]
+    max_lift: Option<usize>,
+    /// Input file, or `-` for stdin. Defaults to `inputs/day05/prod.txt`.
+    #[arg(long)]
+    input: Option<InputSource>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Model {
+    /// The CrateMover 9000 from part 1, which lifts one crate at a time.
+    #[value(name = "9000")]
+    Crane9000,
+    /// The CrateMover 9001 from part 2, which lifts any number.
+    #[value(name = "9001")]
+    Crane9001,
+}
+
+impl Options {
+    /// Every model is a `MaxLift` of some capacity, which saves choosing a
+    /// `Simulation` type per model.
+    fn crane(&self) -> MaxLift {
+        MaxLift(self.max_lift.unwrap_or(match self.crane {
+            Model::Crane9000 => 1,
+            Model::Crane9001 => usize::MAX,
+        }))
+    }
+}
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Render { after, options } => {
+            let (source, input) = super::input(5, &options.input)?;
+            let failed = |error| super::parse_failed(error, 5, &source, &input);
+            let mut simulation = Simulation::new(&input, options.crane()).map_err(failed)?;
+            for _ in 0..after {
+                if simulation.step().map_err(failed)?.is_none() {
+                    break;
+                }
+            }
+            print!("{}", simulation.stacks().render());
+            Ok(())
+        }
+        Command::Animate {
+            delay,
+            frames_dir,
+            options,
+        } => {
+            let (source, input) = super::input(5, &options.input)?;
+            let failed = |error| super::parse_failed(error, 5, &source, &input);
+            let simulation = Simulation::new(&input, options.crane()).map_err(failed)?;
+            if let Some(dir) = &frames_dir {
+                fs::create_dir_all(dir)
+                    .with_context(|| format!("could not create {}", dir.display()))?;
+            }
+            let mut animation = Animation {
+                frames_dir,
+                delay: Duration::from_millis(delay),
+                redraw: io::stdout().is_terminal(),
+                shown: 0,
+            };
+            animation.show("start", simulation.stacks())?;
+            for step in simulation {
+                let step = step.map_err(failed)?;
+                animation.show(&step.block_move.to_string(), &step.stacks)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+struct Animation {
+    frames_dir: Option<PathBuf>,
+    delay: Duration,
+    /// Whether to clear the terminal before each frame rather than printing
+    /// them one after another.
+    redraw: bool,
+    shown: usize,
+}
+
+impl Animation {
+    fn show(&mut self, caption: &str, stacks: &Stacks) -> Result<()> {
+        let frame = format!("{}\n{caption}\n", stacks.render());
+        if let Some(dir) = &self.frames_dir {
+            let path = dir.join(format!("frame-{:04}.txt", self.shown));
+            fs::write(&path, &frame)
+                .with_context(|| format!("could not write {}", path.display()))?;
+        }
+        if self.redraw {
+            print!("\x1b[2J\x1b[H{frame}");
+            thread::sleep(self.delay);
+        } else {
+            println!("{frame}");
+        }
+        self.shown += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use aoc_common::InputSource;
+use clap::{Args, Subcommand};
+use day7::{build_tree, FileSystem, Query};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// List the file system as an indented tree with sizes.
+    Tree(Source),
+    /// List every file and directory with its size, largest first.
+    Du(Source),
+    /// Export the whole hierarchy as JSON.
+    Json(Source),
+    /// List the files and directories matching every condition given.
+    Find {
+        /// Only entries at least this many bytes in total.
+        #[arg(long)]
+        min_size: Option<u64>,
+        /// Only entries at most this many bytes in total.
+        #[arg(long)]
+        max_size: Option<u64>,
+        /// Only entries whose name matches this pattern, which may use `*`
+        /// and `?`.
+        #[arg(long)]
+        name: Option<String>,
+        #[command(flatten)]
+        source: Source,
+    },
+}
+
+#[derive(Args)]
+pub struct Source {
+    /// Input file, or `-` for stdin. Defaults to `inputs/day07/prod.txt`.
+    #[arg(long)]
+    input: Option<InputSource>,
+}
+
+impl Source {
+    fn tree(&self) -> Result<FileSystem> {
+        let (source, input) = super::input(7, &self.input)?;
+        build_tree(&input).map_err(|error| super::parse_failed(error, 7, &source, &input))
+    }
+}
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Tree(source) => print!("{}", source.tree()?.to_tree()),
+        Command::Du(source) => print!("{}", source.tree()?.to_du()),
+        Command::Json(source) => println!("{}", source.tree()?.to_json()),
+        Command::Find {
+            min_size,
+            max_size,
+            name,
+            source,
+        } => {
+            let query = Query {
+                min_size,
+                max_size,
+                name,
+            };
+            for entry in source.tree()?.find(&query) {
+                println!("{}\t{}", entry.size, entry.path);
+            }
+        }
+    }
+    Ok(())
+}
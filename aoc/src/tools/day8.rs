@@ -0,0 +1,100 @@
+use anyhow::{Context, Result};
+use aoc_common::InputSource;
+use clap::{Args, Subcommand, ValueEnum};
+use day8::{to_pgm, to_ppm, Forest, Layer, Overlay};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Draw the forest in colour, shaded by height.
+    Heatmap {
+        /// Show trees visible from outside in bold and dim the rest.
+        #[arg(long)]
+        visible: bool,
+        /// Highlight the best treehouse and the trees it can see.
+        #[arg(long)]
+        treehouse: bool,
+        #[command(flatten)]
+        source: Source,
+    },
+    /// Write the heights or scenic scores as an image, one pixel per tree.
+    Export {
+        #[arg(long, value_enum, default_value_t = ImageLayer::Heights)]
+        layer: ImageLayer,
+        #[arg(long, value_enum, default_value_t = Format::Pgm)]
+        format: Format,
+        /// File to write the image to.
+        #[arg(long)]
+        output: PathBuf,
+        #[command(flatten)]
+        source: Source,
+    },
+}
+
+#[derive(Args)]
+pub struct Source {
+    /// Input file, or `-` for stdin. Defaults to `inputs/day08/prod.txt`.
+    #[arg(long)]
+    input: Option<InputSource>,
+}
+
+impl Source {
+    fn forest(&self) -> Result<Forest> {
+        let (source, input) = super::input(8, &self.input)?;
+        input
+            .parse()
+            .map_err(|error| super::parse_failed(error, 8, &source, &input))
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ImageLayer {
+    Heights,
+    Scenic,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// Greyscale.
+    Pgm,
+    /// Coloured like the heatmap.
+    Ppm,
+}
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Heatmap {
+            visible,
+            treehouse,
+            source,
+        } => {
+            let forest = source.forest()?;
+            let overlay = Overlay {
+                visibility: visible,
+                treehouse,
+            };
+            print!("{}", forest.heatmap(&forest.survey(), overlay));
+        }
+        Command::Export {
+            layer,
+            format,
+            output,
+            source,
+        } => {
+            let forest = source.forest()?;
+            let layer = match layer {
+                ImageLayer::Heights => Layer::Heights,
+                ImageLayer::Scenic => Layer::ScenicScores,
+            };
+            let levels = forest.levels(&forest.survey(), layer);
+            let image = match format {
+                Format::Pgm => to_pgm(&levels),
+                Format::Ppm => to_ppm(&levels),
+            };
+            fs::write(&output, image)
+                .with_context(|| format!("could not write {}", output.display()))?;
+        }
+    }
+    Ok(())
+}
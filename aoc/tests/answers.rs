@@ -0,0 +1,92 @@
+//! Runs every entry in `inputs/answers.toml` through its day's solver.
+
+use std::fs;
+
+use aoc::solution;
+use aoc_common::{input_path, inputs_dir, Part};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Manifest {
+    answer: Vec<Answer>,
+}
+
+#[derive(Deserialize)]
+struct Answer {
+    day: u8,
+    part: u8,
+    input: String,
+    expected: String,
+    #[serde(default)]
+    slow: bool,
+    ignore: Option<String>,
+}
+
+impl Answer {
+    fn name(&self) -> String {
+        format!("day {} part {} ({})", self.day, self.part, self.input)
+    }
+
+    /// Solves the entry, describing how it went wrong if it did.
+    fn check(&self) -> Result<(), String> {
+        let solution = solution(self.day).ok_or("no such day")?;
+        let part = Part::ALL
+            .get(usize::from(self.part).wrapping_sub(1))
+            .ok_or("part must be 1 or 2")?;
+        let path = input_path(self.day, &self.input);
+        let input = fs::read_to_string(&path)
+            .map_err(|error| format!("could not read {}: {error}", path.display()))?;
+        let answer = solution
+            .solve(*part, &input)
+            .map_err(|error| format!("failed: {error:#}"))?;
+        if answer == self.expected {
+            Ok(())
+        } else if answer.contains('\n') || self.expected.contains('\n') {
+            Err(format!("expected:\n{}\n     got:\n{answer}", self.expected))
+        } else {
+            Err(format!("expected {}, got {answer}", self.expected))
+        }
+    }
+}
+
+fn load_manifest() -> Manifest {
+    let path = inputs_dir().join("answers.toml");
+    let contents = fs::read_to_string(&path).expect("inputs/answers.toml exists");
+    toml::from_str(&contents).expect("inputs/answers.toml is a valid manifest")
+}
+
+/// Checks every entry that `select` picks, then fails with all the mismatches
+/// at once so a single run shows everything that drifted.
+fn check_answers(select: impl Fn(&Answer) -> bool) {
+    let mut failures = Vec::new();
+    for answer in load_manifest()
+        .answer
+        .iter()
+        .filter(|answer| select(answer))
+    {
+        if let Some(reason) = &answer.ignore {
+            eprintln!("skipping {}: {reason}", answer.name());
+            continue;
+        }
+        if let Err(failure) = answer.check() {
+            failures.push(format!("{}: {failure}", answer.name()));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} answers are wrong:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn answers_match() {
+    check_answers(|answer| !answer.slow);
+}
+
+#[test]
+#[ignore = "these take too long for a debug build"]
+fn slow_answers_match() {
+    check_answers(|answer| answer.slow);
+}
@@ -0,0 +1,219 @@
+use aoc_common::ParseError;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalorieError {
+    NotANumber,
+    TooManyCalories,
+}
+
+impl fmt::Display for CalorieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalorieError::NotANumber => write!(f, "calorie count is not a number"),
+            CalorieError::TooManyCalories => write!(f, "this elf carries too many calories"),
+        }
+    }
+}
+
+/// The food one elf is carrying.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Elf {
+    pub calories: i32,
+    pub items: usize,
+}
+
+/// A histogram bucket holding the elves with `start..start + width` calories.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: i64,
+    pub count: usize,
+}
+
+/// Every elf's food, in input order. Elves are identified by their 0-based
+/// index in the input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+}
+
+impl Inventory {
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    pub fn elf(&self, index: usize) -> Option<&Elf> {
+        self.elves.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elves.is_empty()
+    }
+
+    /// How many items each elf is carrying.
+    pub fn item_counts(&self) -> impl Iterator<Item = usize> + '_ {
+        self.elves.iter().map(|elf| elf.items)
+    }
+
+    /// The `k` elves carrying the most calories as `(index, calories)`, most
+    /// first. Ties go to the elf that comes first in the input. Only `k`
+    /// elves are held at a time, so this is cheap for small `k`.
+    pub fn top(&self, k: usize) -> Vec<(usize, i32)> {
+        let mut best = BestElves::new(k);
+        for (index, elf) in self.elves.iter().enumerate() {
+            best.push(index, elf.calories);
+        }
+        best.into_vec()
+    }
+
+    /// The total calories of the `k` best-stocked elves. Each elf's total
+    /// fits in an `i32`, but several together may not.
+    pub fn top_total(&self, k: usize) -> i64 {
+        self.top(k)
+            .iter()
+            .map(|&(_, calories)| i64::from(calories))
+            .sum()
+    }
+
+    /// Where an elf places by calories, starting at 1 for the most. Elves
+    /// carrying the same amount share a rank.
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        let calories = self.elf(index)?.calories;
+        Some(
+            1 + self
+                .elves
+                .iter()
+                .filter(|elf| elf.calories > calories)
+                .count(),
+        )
+    }
+
+    /// The calories at percentile `p` (0 to 100), using the nearest-rank
+    /// method, so the result is always one of the elves' totals.
+    pub fn percentile(&self, p: f64) -> Option<i32> {
+        if self.elves.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let mut calories: Vec<i32> = self.elves.iter().map(|elf| elf.calories).collect();
+        let rank = ((p / 100.0) * calories.len() as f64).ceil() as usize;
+        let (_, &mut value, _) = calories.select_nth_unstable(rank.max(1) - 1);
+        Some(value)
+    }
+
+    /// Counts the elves in `width`-calorie buckets, fewest calories first.
+    /// Only buckets holding at least one elf are returned, so a few elves far
+    /// apart cost no more than a few close together.
+    ///
+    /// Panics if `width` is 0.
+    pub fn histogram(&self, width: u32) -> Vec<Bucket> {
+        assert!(width > 0, "histogram buckets need a width");
+        let width = i64::from(width);
+        let mut counts = BTreeMap::new();
+        for elf in &self.elves {
+            *counts
+                .entry(i64::from(elf.calories).div_euclid(width))
+                .or_insert(0) += 1;
+        }
+        counts
+            .into_iter()
+            .map(|(bucket, count)| Bucket {
+                start: bucket * width,
+                count,
+            })
+            .collect()
+    }
+}
+
+/// Reads the whole list in one pass. Blank lines separate elves, and runs of
+/// them do not add empty elves.
+impl FromStr for Inventory {
+    type Err = ParseError<CalorieError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elves = Vec::new();
+        let mut builder = ElfBuilder::default();
+        for line in s.lines() {
+            let finished = builder
+                .line(line)
+                .map_err(|(kind, text)| ParseError::at(kind, s, text))?;
+            elves.extend(finished);
+        }
+        elves.extend(builder.finish());
+        Ok(Inventory { elves })
+    }
+}
+
+/// Adds up one elf at a time from lines of input, handing each elf back once
+/// a blank line or the end of the input finishes it.
+#[derive(Default)]
+pub(crate) struct ElfBuilder {
+    current: Option<Elf>,
+}
+
+impl ElfBuilder {
+    /// Takes the next line, failing with the part of it that is at fault.
+    pub(crate) fn line<'a>(
+        &mut self,
+        line: &'a str,
+    ) -> Result<Option<Elf>, (CalorieError, &'a str)> {
+        let text = line.trim();
+        if text.is_empty() {
+            return Ok(self.current.take());
+        }
+        let calories: i32 = text.parse().map_err(|_| (CalorieError::NotANumber, text))?;
+        let elf = self.current.get_or_insert_with(Elf::default);
+        elf.calories = elf
+            .calories
+            .checked_add(calories)
+            .ok_or((CalorieError::TooManyCalories, text))?;
+        elf.items += 1;
+        Ok(None)
+    }
+
+    pub(crate) fn finish(self) -> Option<Elf> {
+        self.current
+    }
+}
+
+/// The `k` best-stocked elves seen so far. They sit in a min-heap so the
+/// weakest is always the one pushed out by a better elf.
+pub(crate) struct BestElves {
+    k: usize,
+    heap: BinaryHeap<Reverse<(i32, Reverse<usize>)>>,
+}
+
+impl BestElves {
+    pub(crate) fn new(k: usize) -> Self {
+        BestElves {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    /// Ties are won by the elf pushed first.
+    pub(crate) fn push(&mut self, index: usize, calories: i32) {
+        if self.k == 0 {
+            return;
+        }
+        self.heap.push(Reverse((calories, Reverse(index))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The elves as `(index, calories)`, most calories first.
+    pub(crate) fn into_vec(self) -> Vec<(usize, i32)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| (index, calories))
+            .collect()
+    }
+}
@@ -0,0 +1,237 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, Solution};
+
+mod inventory;
+mod stream;
+pub use inventory::*;
+pub use stream::*;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(report)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(report)?.to_string())
+    }
+}
+
+fn report(error: StreamError) -> anyhow::Error {
+    match error {
+        StreamError::Parse(error) => Diagnostic::from(error).into(),
+        StreamError::Io(error) => error.into(),
+    }
+}
+
+pub fn part_2(input: &str) -> Result<i64, StreamError> {
+    Ok(TopElves::read(input.as_bytes(), 3)?.total())
+}
+
+pub fn part_1(input: &str) -> Result<i64, StreamError> {
+    Ok(TopElves::read(input.as_bytes(), 1)?.total())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use aoc_common::ParseError;
+
+    fn given() -> Inventory {
+        include_str!("../../inputs/day01/dev.txt").parse().unwrap()
+    }
+
+    #[test]
+    fn elf_calories_basic() {
+        let str = "1\n1\n1\n1";
+
+        let inventory: Inventory = str.parse().unwrap();
+
+        assert_eq!(
+            inventory.elves(),
+            &[Elf {
+                calories: 4,
+                items: 4
+            }]
+        );
+    }
+    #[test]
+    fn elf_calories_trailing_newline() {
+        let str = "1\n1\n1\n1\n";
+
+        let inventory: Inventory = str.parse().unwrap();
+
+        assert_eq!(
+            inventory.elves(),
+            &[Elf {
+                calories: 4,
+                items: 4
+            }]
+        );
+    }
+
+    #[test]
+    fn part_1_and_2_given() {
+        let input = include_str!("../../inputs/day01/dev.txt");
+
+        assert_eq!(part_1(input).unwrap(), 24000);
+        assert_eq!(part_2(input).unwrap(), 45000);
+    }
+
+    #[test]
+    fn top_elves_keep_their_indices() {
+        let inventory = given();
+
+        assert_eq!(inventory.top(2), vec![(3, 24000), (2, 11000)]);
+        assert_eq!(inventory.top(10).len(), 5);
+        assert!(inventory.top(0).is_empty());
+    }
+
+    #[test]
+    fn ties_share_a_rank() {
+        let inventory: Inventory = "5\n\n7\n\n5\n\n1".parse().unwrap();
+
+        let ranks: Vec<_> = (0..5).map(|index| inventory.rank(index)).collect();
+
+        assert_eq!(ranks, vec![Some(2), Some(1), Some(2), Some(4), None]);
+        assert_eq!(inventory.top(3), vec![(1, 7), (0, 5), (2, 5)]);
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let inventory = given();
+
+        assert_eq!(inventory.percentile(0.0), Some(4000));
+        assert_eq!(inventory.percentile(50.0), Some(10000));
+        assert_eq!(inventory.percentile(100.0), Some(24000));
+        assert_eq!(inventory.percentile(101.0), None);
+        assert_eq!(Inventory::default().percentile(50.0), None);
+    }
+
+    #[test]
+    fn histogram_skips_empty_buckets() {
+        let inventory = given();
+
+        let histogram = inventory.histogram(10000);
+
+        assert_eq!(
+            histogram,
+            vec![
+                Bucket { start: 0, count: 2 },
+                Bucket {
+                    start: 10000,
+                    count: 2
+                },
+                Bucket {
+                    start: 20000,
+                    count: 1
+                },
+            ]
+        );
+        let starts: Vec<i64> = inventory
+            .histogram(5000)
+            .iter()
+            .map(|bucket| bucket.start)
+            .collect();
+        assert_eq!(starts, vec![0, 5000, 10000, 20000]);
+    }
+
+    #[test]
+    fn distant_elves_make_few_buckets() {
+        let inventory: Inventory = "-2147483648\n\n2147483647".parse().unwrap();
+
+        assert_eq!(
+            inventory.histogram(3),
+            vec![
+                Bucket {
+                    start: -2147483649,
+                    count: 1
+                },
+                Bucket {
+                    start: 2147483646,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(inventory.top_total(2), -1);
+    }
+
+    #[test]
+    fn top_totals_do_not_overflow() {
+        let input = "2147483647\n\n2147483647\n\n2147483647";
+        let inventory: Inventory = input.parse().unwrap();
+
+        assert_eq!(inventory.top_total(3), 3 * 2147483647);
+        assert_eq!(part_2(input).unwrap(), 3 * 2147483647);
+    }
+
+    #[test]
+    fn item_counts_per_elf() {
+        let inventory = given();
+
+        assert_eq!(
+            inventory.item_counts().collect::<Vec<_>>(),
+            vec![3, 1, 2, 3, 1]
+        );
+    }
+
+    #[test]
+    fn bad_calories_are_located() {
+        let error = "1\n2\n\n3\nfour\n".parse::<Inventory>().unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(CalorieError::NotANumber, 5, 1, "four")
+        );
+    }
+
+    #[test]
+    fn streams_crlf_and_trailing_blank_lines() {
+        let input = "1000\r\n2000\r\n\r\n4000\r\n\r\n5000\r\n6000\r\n\r\n\r\n";
+
+        let summary = TopElves::read(input.as_bytes(), 2).unwrap();
+
+        assert_eq!(
+            summary,
+            TopElves {
+                top: vec![(2, 11000), (1, 4000)],
+                elves: 3,
+                items: 5,
+            }
+        );
+        assert_eq!(summary.total(), 15000);
+    }
+
+    #[test]
+    fn streamed_totals_match_the_inventory() {
+        let input = include_str!("../../inputs/day01/prod.txt");
+        let inventory: Inventory = input.parse().unwrap();
+
+        let summary = TopElves::read(input.as_bytes(), 3).unwrap();
+
+        assert_eq!(summary.top, inventory.top(3));
+        assert_eq!(summary.elves, inventory.len());
+    }
+
+    #[test]
+    fn streamed_errors_are_located() {
+        let Err(StreamError::Parse(error)) = part_2("1\r\n\r\n  12x4\r\n") else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(
+            error,
+            ParseError::new(CalorieError::NotANumber, 3, 3, "12x4")
+        );
+    }
+
+    #[test]
+    fn overflowing_elves_are_errors() {
+        let error = "2147483647\n1".parse::<Inventory>().unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(CalorieError::TooManyCalories, 2, 1, "1")
+        );
+    }
+}
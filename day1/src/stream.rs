@@ -0,0 +1,100 @@
+use crate::inventory::{BestElves, ElfBuilder};
+use crate::{CalorieError, Elf};
+use aoc_common::ParseError;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Why a streamed inventory could not be read.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError<CalorieError>),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "could not read the inventory: {error}"),
+            StreamError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Io(error) => Some(error),
+            StreamError::Parse(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError<CalorieError>> for StreamError {
+    fn from(error: ParseError<CalorieError>) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+/// What is left of an inventory after streaming through it: the `k` elves
+/// carrying the most calories and a count of everything else.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TopElves {
+    /// `(index, calories)` for the best-stocked elves, most first.
+    pub top: Vec<(usize, i32)>,
+    pub elves: usize,
+    pub items: usize,
+}
+
+impl TopElves {
+    /// Reads an inventory one line at a time, holding only the current line
+    /// and the best `k` elves, so inputs of any size fit in memory.
+    ///
+    /// Lines may end in `\n` or `\r\n`, and blank lines anywhere (including
+    /// at the end) only separate elves.
+    pub fn read<R: BufRead>(mut reader: R, k: usize) -> Result<Self, StreamError> {
+        let mut best = BestElves::new(k);
+        let mut summary = TopElves::default();
+        let mut builder = ElfBuilder::default();
+        let mut line = String::new();
+        let mut number = 0;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            number += 1;
+            let text = line.trim_end_matches(['\n', '\r']);
+            let elf = builder
+                .line(text)
+                .map_err(|(kind, bad)| ParseError::at(kind, text, bad).offset_lines(number - 1))?;
+            summary.count(&mut best, elf);
+        }
+        summary.count(&mut best, builder.finish());
+
+        summary.top = best.into_vec();
+        Ok(summary)
+    }
+
+    fn count(&mut self, best: &mut BestElves, elf: Option<Elf>) {
+        if let Some(elf) = elf {
+            best.push(self.elves, elf.calories);
+            self.elves += 1;
+            self.items += elf.items;
+        }
+    }
+
+    /// The total calories of the elves kept, which may not fit in an `i32`
+    /// even though each elf's does.
+    pub fn total(&self) -> i64 {
+        self.top
+            .iter()
+            .map(|&(_, calories)| i64::from(calories))
+            .sum()
+    }
+}
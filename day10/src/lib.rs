@@ -0,0 +1,184 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::{parse_lines, Diagnostic, ParseError, Solution};
+use tracing::debug;
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        let screen = part_2(input).map_err(Diagnostic::from)?;
+        let rows: Vec<String> = screen
+            .chunks(SCREEN_WIDTH)
+            .map(|row| row.iter().collect())
+            .collect();
+        Ok(rows.join("\n"))
+    }
+}
+
+enum Command {
+    Noop,
+    Addx(i32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstructionError {
+    UnknownInstruction,
+    NotANumber,
+    TooManyCycles,
+}
+
+impl fmt::Display for InstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstructionError::UnknownInstruction => write!(f, "expected `noop` or `addx <value>`"),
+            InstructionError::NotANumber => write!(f, "addx value is not a number"),
+            InstructionError::TooManyCycles => {
+                write!(f, "program runs past the end of the screen")
+            }
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseError<InstructionError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            None if s == "noop" => Ok(Command::Noop),
+            Some(("addx", value)) => value
+                .parse()
+                .map(Command::Addx)
+                .map_err(|_| ParseError::at(InstructionError::NotANumber, s, value)),
+            _ => Err(ParseError::at(InstructionError::UnknownInstruction, s, s)),
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<i32, ParseError<InstructionError>> {
+    let mut x = 1;
+    let mut cycle_count = 0;
+
+    let mut option_to_values = Vec::new();
+
+    for command in parse_lines::<Command, _>(input)? {
+        match command {
+            Command::Noop => {
+                cycle_count += 1;
+                option_to_values.push(get_v_if_necessary(&x, &cycle_count));
+            }
+            Command::Addx(v) => {
+                cycle_count += 1;
+                option_to_values.push(get_v_if_necessary(&x, &cycle_count));
+                cycle_count += 1;
+                option_to_values.push(get_v_if_necessary(&x, &cycle_count));
+                x += v;
+            }
+        }
+    }
+    let values = option_to_values
+        .into_iter()
+        .flatten()
+        .filter(|val| [20, 60, 100, 140, 180, 220].contains(&val.1))
+        .map(|val| val.0)
+        .collect::<Vec<i32>>();
+
+    debug!(?values, "signal strengths at the sampled cycles");
+
+    let signal_strength: i32 = values.into_iter().sum();
+    Ok(signal_strength)
+}
+
+pub fn part_2(input: &str) -> Result<Vec<char>, ParseError<InstructionError>> {
+    let mut screen: Vec<char> = vec!['.'; SCREEN_WIDTH * SCREEN_HEIGHT];
+    let mut x = 1;
+    let mut cycle_count = 0;
+
+    for (index, line) in input.lines().enumerate() {
+        let command: Command = line
+            .parse()
+            .map_err(|error: ParseError<_>| error.offset_lines(index))?;
+        let cycles = match command {
+            Command::Noop => 1,
+            Command::Addx(_) => 2,
+        };
+        if cycle_count + cycles > screen.len() {
+            return Err(
+                ParseError::at(InstructionError::TooManyCycles, line, line).offset_lines(index)
+            );
+        }
+        match command {
+            Command::Noop => {
+                screen[cycle_count] = sprite_overlaps_cycle(&x, &(cycle_count as i32));
+                cycle_count += 1;
+            }
+            Command::Addx(v) => {
+                screen[cycle_count] = sprite_overlaps_cycle(&x, &(cycle_count as i32));
+                cycle_count += 1;
+                screen[cycle_count] = sprite_overlaps_cycle(&x, &(cycle_count as i32));
+                cycle_count += 1;
+                x += v;
+            }
+        }
+    }
+
+    Ok(screen)
+}
+
+fn sprite_overlaps_cycle(x: &i32, cycle_count: &i32) -> char {
+    if (cycle_count % 40 - x).abs() <= 1 {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+fn get_v_if_necessary(x: &i32, cycle_count: &i32) -> Option<(i32, i32)> {
+    if cycle_count % 20 == 0 {
+        Some((*x * *cycle_count, *cycle_count))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day10/dev.txt");
+
+        let result = part_1(input).unwrap();
+
+        assert_eq!(result, 13140);
+    }
+
+    #[test]
+    fn part_2_given() {
+        let input = include_str!("../../inputs/day10/dev.txt");
+        let expected_result: Vec<char> = "##..##..##..##..##..##..##..##..##..##..###...###...###...###...###...###...###.####....####....####....####....####....#####.....#####.....#####.....#####.....######......######......######......###########.......#######.......#######.....".chars().collect();
+
+        let result = part_2(input).unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn bad_instruction_is_located() {
+        let error = part_1("noop\naddx 3\naddx -\n").unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(InstructionError::NotANumber, 3, 6, "-")
+        );
+    }
+}
@@ -0,0 +1,393 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::{collections::VecDeque, str::FromStr};
+use tracing::{debug, trace};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+#[derive(Debug)]
+enum NumOrOld {
+    Num(i64),
+    Old,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MonkeyError {
+    MissingField(&'static str),
+    NotANumber,
+    UnknownOperation,
+    UnknownMonkey(usize),
+    ThrowsToItself,
+    ZeroDivisor,
+    NotEnoughMonkeys,
+}
+
+impl fmt::Display for MonkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MonkeyError::MissingField(field) => write!(f, "expected `{field}:`"),
+            MonkeyError::NotANumber => write!(f, "expected a number"),
+            MonkeyError::UnknownOperation => {
+                write!(f, "operation should look like `new = old * 19`")
+            }
+            MonkeyError::UnknownMonkey(target) => {
+                write!(
+                    f,
+                    "this monkey throws to monkey {target}, which does not exist"
+                )
+            }
+            MonkeyError::ThrowsToItself => write!(f, "a monkey cannot throw to itself"),
+            MonkeyError::ZeroDivisor => write!(f, "cannot test divisibility by zero"),
+            MonkeyError::NotEnoughMonkeys => write!(f, "need at least two monkeys"),
+        }
+    }
+}
+
+fn parse_number<T: FromStr>(s: &str, text: &str) -> Result<T, ParseError<MonkeyError>> {
+    text.parse()
+        .map_err(|_| ParseError::at(MonkeyError::NotANumber, s, text))
+}
+
+/// Parses the number at the end of a line like `Test: divisible by 23`.
+fn last_number<T: FromStr>(s: &str, value: &str) -> Result<T, ParseError<MonkeyError>> {
+    parse_number(s, value.rsplit(' ').next().unwrap_or(value))
+}
+
+impl FromStr for NumOrOld {
+    type Err = ParseError<MonkeyError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "old" => NumOrOld::Old,
+            _ => NumOrOld::Num(parse_number(s, s)?),
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Operation {
+    Add(NumOrOld),
+    Multiply(NumOrOld),
+}
+
+impl FromStr for Operation {
+    type Err = ParseError<MonkeyError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || ParseError::at(MonkeyError::UnknownOperation, s, s);
+        let (operator, operand) = s
+            .trim()
+            .strip_prefix("new = old ")
+            .and_then(|rest| rest.split_once(' '))
+            .ok_or_else(unknown)?;
+        let num_or_old: NumOrOld = operand
+            .parse()
+            .map_err(|error: ParseError<_>| error.within(s, operand))?;
+
+        match operator {
+            "+" => Ok(Self::Add(num_or_old)),
+            "*" => Ok(Self::Multiply(num_or_old)),
+            _ => Err(unknown()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Test {
+    divisible_by: i64,
+    true_monkey: usize,
+    false_monkey: usize,
+}
+
+impl Test {
+    fn find_next_monkey(&self, value: &i64) -> usize {
+        if value % self.divisible_by == 0 {
+            self.true_monkey
+        } else {
+            self.false_monkey
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Monkey {
+    items: VecDeque<i64>,
+    operation: Operation,
+    test: Test,
+    inspected_items: u64,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError<MonkeyError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().skip(1);
+        let mut field = |name: &'static str| {
+            let line = lines.next().unwrap_or(&s[s.len()..]);
+            line.trim_start()
+                .strip_prefix(name)
+                .and_then(|rest| rest.strip_prefix(':'))
+                .map(str::trim)
+                .ok_or_else(|| ParseError::at(MonkeyError::MissingField(name), s, line))
+        };
+
+        let starting_items = field("Starting items")?;
+        let starting_items = if starting_items.is_empty() {
+            VecDeque::new()
+        } else {
+            starting_items
+                .split(',')
+                .map(|elem| parse_number(s, elem.trim()))
+                .collect::<Result<_, _>>()?
+        };
+        let operation = field("Operation")?;
+        let operation: Operation = operation
+            .parse()
+            .map_err(|error: ParseError<_>| error.within(s, operation))?;
+        let test = Test {
+            divisible_by: last_number(s, field("Test")?)?,
+            true_monkey: last_number(s, field("If true")?)?,
+            false_monkey: last_number(s, field("If false")?)?,
+        };
+        Ok(Self {
+            items: starting_items,
+            operation,
+            test,
+            inspected_items: 0,
+        })
+    }
+}
+
+impl Monkey {
+    fn inspect_items(&mut self, other_monkeys: &[Rc<RefCell<Monkey>>], relief: Relief) {
+        while !self.items.is_empty() {
+            let item = self.items.pop_front().unwrap();
+            let higher_stress: i64 = match &self.operation {
+                Operation::Add(num_or_old) => {
+                    item + match num_or_old {
+                        NumOrOld::Old => item,
+                        NumOrOld::Num(other_num) => *other_num,
+                    }
+                }
+                Operation::Multiply(num_or_old) => {
+                    item * match num_or_old {
+                        NumOrOld::Old => item,
+                        NumOrOld::Num(other_num) => *other_num,
+                    }
+                }
+            };
+            let new_stress = match relief {
+                Relief::DivideByThree => higher_stress / 3,
+                Relief::Modulo(lcm) => higher_stress % lcm,
+            };
+            let test_result = self.test.find_next_monkey(&new_stress);
+            trace!(item, worry = new_stress, to = test_result, "threw an item");
+            let mut target_monkey = other_monkeys[test_result].borrow_mut();
+            target_monkey.items.push_back(new_stress);
+            self.inspected_items += 1;
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Relief {
+    DivideByThree,
+    Modulo(i64),
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError<MonkeyError>> {
+    monkey_business(input, 20, |_| Relief::DivideByThree)
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError<MonkeyError>> {
+    monkey_business(input, 10000, Relief::Modulo)
+}
+
+fn monkey_business(
+    input: &str,
+    rounds: usize,
+    relief: fn(i64) -> Relief,
+) -> Result<i64, ParseError<MonkeyError>> {
+    let mut monkeys: Vec<Rc<RefCell<Monkey>>> = parse_monkeys(input)?
+        .into_iter()
+        .map(|monkey| Rc::new(RefCell::new(monkey)))
+        .collect();
+    debug!(monkeys = monkeys.len(), "parsed monkeys");
+
+    let lcm = monkeys
+        .iter()
+        .map(|monkey| monkey.borrow().test.divisible_by)
+        .product();
+    debug!(lcm, "found the common multiple of the tests");
+    let relief = relief(lcm);
+    for _ in 0..rounds {
+        for monkey in &monkeys {
+            monkey.borrow_mut().inspect_items(&monkeys, relief);
+        }
+    }
+    monkeys.sort_by(|left, right| {
+        left.borrow()
+            .inspected_items
+            .cmp(&right.borrow().inspected_items)
+    });
+
+    let (Some(top_monkey), Some(second_top_monkey)) = (monkeys.pop(), monkeys.pop()) else {
+        let end = &input[input.len()..];
+        return Err(ParseError::at(MonkeyError::NotEnoughMonkeys, input, end));
+    };
+    debug!(
+        first = top_monkey.borrow().inspected_items,
+        second = second_top_monkey.borrow().inspected_items,
+        "found the two busiest monkeys"
+    );
+
+    let result = top_monkey.borrow().inspected_items * second_top_monkey.borrow().inspected_items;
+
+    Ok(result as i64)
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError<MonkeyError>> {
+    let blocks: Vec<&str> = input
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .collect();
+    let monkeys = blocks
+        .iter()
+        .map(|block| {
+            block
+                .parse::<Monkey>()
+                .map_err(|error| error.within(input, block))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for (index, (block, monkey)) in blocks.iter().zip(&monkeys).enumerate() {
+        let field = |name: &str| {
+            block
+                .lines()
+                .map(str::trim_start)
+                .find(|line| line.starts_with(name))
+                .unwrap_or(block)
+        };
+        if monkey.test.divisible_by == 0 {
+            return Err(ParseError::at(
+                MonkeyError::ZeroDivisor,
+                input,
+                field("Test"),
+            ));
+        }
+        for (name, target) in [
+            ("If true", monkey.test.true_monkey),
+            ("If false", monkey.test.false_monkey),
+        ] {
+            if target >= monkeys.len() {
+                let header = block.lines().next().unwrap_or_default();
+                return Err(ParseError::at(
+                    MonkeyError::UnknownMonkey(target),
+                    input,
+                    header,
+                ));
+            }
+            if target == index {
+                return Err(ParseError::at(
+                    MonkeyError::ThrowsToItself,
+                    input,
+                    field(name),
+                ));
+            }
+        }
+    }
+    Ok(monkeys)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day11/dev.txt");
+
+        let result = part_1(input).unwrap();
+
+        assert_eq!(result, 10605);
+    }
+
+    #[test]
+    fn part_2_given() {
+        let input = include_str!("../../inputs/day11/dev.txt");
+
+        let result = part_2(input).unwrap();
+
+        assert_eq!(result, 2713310158);
+    }
+
+    #[test]
+    fn bad_operation_is_located() {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old ^ 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+
+        let error = part_1(input).unwrap_err();
+
+        assert_eq!(error.kind, MonkeyError::UnknownOperation);
+        assert_eq!((error.line, error.column), (3, 14));
+    }
+
+    #[test]
+    fn bad_item_is_located() {
+        let input = include_str!("../../inputs/day11/dev.txt").replacen("79", "7x", 1);
+
+        let error = part_1(&input).unwrap_err();
+
+        assert_eq!(error, ParseError::new(MonkeyError::NotANumber, 2, 19, "7x"));
+    }
+
+    #[test]
+    fn throwing_to_itself_is_located() {
+        let input = include_str!("../../inputs/day11/dev.txt").replacen(
+            "If true: throw to monkey 2",
+            "If true: throw to monkey 0",
+            1,
+        );
+
+        let error = part_1(&input).unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(
+                MonkeyError::ThrowsToItself,
+                5,
+                5,
+                "If true: throw to monkey 0"
+            )
+        );
+    }
+
+    #[test]
+    fn zero_divisor_is_located() {
+        let input = include_str!("../../inputs/day11/dev.txt").replacen(
+            "divisible by 19",
+            "divisible by 0",
+            1,
+        );
+
+        let error = part_2(&input).unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(MonkeyError::ZeroDivisor, 11, 3, "Test: divisible by 0")
+        );
+    }
+}
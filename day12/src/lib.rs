@@ -0,0 +1,291 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{Diagnostic, Grid, ParseError, Point, RaggedGridError, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+enum Height {
+    Start,
+    End,
+    Normal(char),
+}
+
+struct HeightMap {
+    map: Grid<Height>,
+    path_stack: VecDeque<Path>,
+    considered_paths: Vec<Path>,
+    finished_paths: Vec<Path>,
+}
+
+#[derive(Clone, Debug)]
+struct Path {
+    history: HashSet<Point>,
+    current: Point,
+    height: Height,
+}
+
+impl Path {
+    fn new(current: Point, height: Height) -> Self {
+        Self {
+            history: HashSet::new(),
+            current,
+            height,
+        }
+    }
+    fn length(&self) -> usize {
+        self.history.len()
+    }
+    fn add_new_position(&self, new_position: Point, new_height: Height) -> Option<Self> {
+        let mut new_history = self.history.clone();
+        new_history.insert(self.current);
+        if !self.height.can_move_to(&new_height) {
+            return None;
+        }
+        Some(Self {
+            history: new_history,
+            current: new_position,
+            height: new_height,
+        })
+    }
+    fn is_finished(&self) -> bool {
+        matches!(self.height, Height::End)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeightMapError {
+    NotAHeight,
+    RaggedRows,
+    MissingStart,
+    MissingEnd,
+}
+
+impl fmt::Display for HeightMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeightMapError::NotAHeight => write!(f, "heights should be a-z, S or E"),
+            HeightMapError::RaggedRows => write!(f, "rows are not all the same width"),
+            HeightMapError::MissingStart => write!(f, "map has no start (S)"),
+            HeightMapError::MissingEnd => write!(f, "map has no end (E)"),
+        }
+    }
+}
+
+impl From<RaggedGridError> for HeightMapError {
+    fn from(_: RaggedGridError) -> Self {
+        HeightMapError::RaggedRows
+    }
+}
+
+impl TryFrom<char> for Height {
+    type Error = HeightMapError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(Height::Start),
+            'E' => Ok(Height::End),
+            'a'..='z' => Ok(Height::Normal(c)),
+            _ => Err(HeightMapError::NotAHeight),
+        }
+    }
+}
+
+impl FromStr for HeightMap {
+    type Err = ParseError<HeightMapError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::try_parse(s, Height::try_from)?;
+        for (height, missing) in [
+            (Height::Start, HeightMapError::MissingStart),
+            (Height::End, HeightMapError::MissingEnd),
+        ] {
+            if map.position(|&cell| cell == height).is_none() {
+                return Err(ParseError::at(missing, s, &s[s.len()..]));
+            }
+        }
+
+        let stack = VecDeque::new();
+
+        Ok(Self {
+            map,
+            path_stack: stack,
+            considered_paths: Vec::new(),
+            finished_paths: Vec::new(),
+        })
+    }
+}
+
+impl HeightMap {
+    fn initialize_s_start(&mut self) {
+        if let Some(starting_position) = self.map.position(|height| *height == Height::Start) {
+            self.path_stack
+                .push_back(Path::new(starting_position, Height::Start));
+        }
+    }
+    fn initialize_a_start(&mut self) {
+        let starting_positions: Vec<(Point, &Height)> = self
+            .map
+            .iter()
+            .filter(|(_, height)| match height {
+                Height::Start => true,
+                Height::End => false,
+                Height::Normal(height) => matches!(height, 'a'),
+            })
+            .collect();
+        for (starting_position, height) in starting_positions {
+            self.path_stack
+                .push_back(Path::new(starting_position, *height));
+        }
+    }
+    fn next_path(&mut self) -> Option<Path> {
+        let path = self.path_stack.pop_front()?;
+        self.considered_paths.push(path.clone());
+        Some(path)
+    }
+    fn all_valid_new_paths(&self, base: Path) -> Vec<Path> {
+        let all_valid_paths: Vec<Path> = self
+            .map
+            .neighbours4(base.current)
+            .flat_map(|position| base.add_new_position(position, self.map[position]))
+            .filter(|new_path| {
+                let shorter_path_has_been_considered =
+                    self.considered_paths.iter().any(|existing_path| {
+                        existing_path.current == new_path.current
+                            && existing_path.length() <= new_path.length()
+                    });
+                !shorter_path_has_been_considered
+            })
+            .filter(|new_path| {
+                let shorter_path_exists = self.path_stack.iter().any(|existing_path| {
+                    existing_path.current == new_path.current
+                        && existing_path.length() <= new_path.length()
+                });
+                !shorter_path_exists
+            })
+            .collect();
+        //println!("Valid Paths are {:?}", all_valid_paths);
+        all_valid_paths
+    }
+}
+
+impl Height {
+    fn convert_height(&self) -> i32 {
+        (match self {
+            Height::Start => 'a',
+            Height::End => 'z',
+            Height::Normal(height) => *height,
+        }) as i32
+    }
+    fn can_move_to(&self, other: &Height) -> bool {
+        let self_height = self.convert_height();
+        let other_height = other.convert_height();
+        self_height - other_height >= -1
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let mut height_map: HeightMap = input.parse().map_err(Diagnostic::from)?;
+    height_map.initialize_s_start();
+    while !height_map.path_stack.is_empty() {
+        let Some(path) = height_map.next_path() else {
+            break;
+        };
+        if path.is_finished() {
+            height_map.finished_paths.push(path.clone());
+            continue;
+        }
+        let new_paths = height_map.all_valid_new_paths(path);
+        for new_path in new_paths {
+            height_map.path_stack.push_back(new_path);
+        }
+    }
+    let shortest_path: &Path = height_map
+        .finished_paths
+        .iter()
+        .reduce(|accum, elem| {
+            if elem.length() < accum.length() {
+                elem
+            } else {
+                accum
+            }
+        })
+        .ok_or_else(|| anyhow!("no path reaches the end"))?;
+    Ok(shortest_path.length())
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    let mut height_map: HeightMap = input.parse().map_err(Diagnostic::from)?;
+    height_map.initialize_a_start();
+    while !height_map.path_stack.is_empty() {
+        let Some(path) = height_map.next_path() else {
+            break;
+        };
+        if path.is_finished() {
+            height_map.finished_paths.push(path.clone());
+            continue;
+        }
+        let new_paths = height_map.all_valid_new_paths(path);
+        for new_path in new_paths {
+            height_map.path_stack.push_back(new_path);
+        }
+    }
+    let shortest_path: &Path = height_map
+        .finished_paths
+        .iter()
+        .reduce(|accum, elem| {
+            if elem.length() < accum.length() {
+                elem
+            } else {
+                accum
+            }
+        })
+        .ok_or_else(|| anyhow!("no path reaches the end"))?;
+    Ok(shortest_path.length())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part1_given() {
+        let input = include_str!("../../inputs/day12/dev.txt");
+
+        let result = part_1(input).unwrap();
+
+        assert_eq!(result, 31);
+    }
+
+    #[test]
+    fn part2_given() {
+        let input = include_str!("../../inputs/day12/dev.txt");
+
+        let result = part_2(input).unwrap();
+
+        assert_eq!(result, 29);
+    }
+
+    #[test]
+    fn bad_height_is_located() {
+        let error = "Sab\nc#E".parse::<HeightMap>().err().unwrap();
+
+        assert_eq!(
+            error,
+            ParseError::new(HeightMapError::NotAHeight, 2, 2, "#")
+        );
+    }
+}
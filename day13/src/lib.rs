@@ -0,0 +1,233 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+use std::fmt;
+use tracing::{debug, trace};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+#[derive(Debug)]
+struct Pair {
+    left: Value,
+    right: Value,
+}
+
+fn value_in_order(left: &Value, right: &Value) -> Ordering {
+    trace!(%left, %right, "comparing values");
+    let mut result: Ordering = Ordering::Equal;
+    if let Value::Number(left) = left {
+        if let Value::Number(right) = right {
+            result = num_in_order(left, right);
+        } else if let Value::Array(right_arr) = right {
+            let left_arr = vec![Value::Number(left.clone())];
+            result = vec_in_order(&left_arr, right_arr);
+        }
+    }
+
+    match result {
+        Ordering::Less => return Ordering::Less,
+        Ordering::Greater => return Ordering::Greater,
+        Ordering::Equal => {}
+    }
+
+    if let Value::Number(right) = right {
+        if let Value::Number(left) = left {
+            result = num_in_order(left, right);
+        } else if let Value::Array(left_arr) = left {
+            let right_arr = vec![Value::Number(right.clone())];
+            result = vec_in_order(left_arr, &right_arr);
+        }
+    }
+    match result {
+        Ordering::Less => return Ordering::Less,
+        Ordering::Greater => return Ordering::Greater,
+        Ordering::Equal => {}
+    }
+
+    if let Value::Array(right_arr) = right {
+        if let Value::Number(left) = left {
+            let left_arr = vec![Value::Number(left.clone())];
+            result = vec_in_order(&left_arr, right_arr);
+        } else if let Value::Array(left_arr) = left {
+            result = vec_in_order(left_arr, right_arr);
+        }
+    }
+    match result {
+        Ordering::Less => return Ordering::Less,
+        Ordering::Greater => return Ordering::Greater,
+        Ordering::Equal => {}
+    }
+
+    if let Value::Array(left_arr) = left {
+        if let Value::Number(right) = right {
+            let right_arr = vec![Value::Number(right.clone())];
+            result = vec_in_order(left_arr, &right_arr);
+        } else if let Value::Array(right_arr) = right {
+            result = vec_in_order(left_arr, right_arr);
+        }
+    }
+
+    result
+}
+
+fn num_in_order(left: &serde_json::Number, right: &serde_json::Number) -> Ordering {
+    trace!(%left, %right, "comparing numbers");
+    let left = left.as_u64();
+    let right = right.as_u64();
+    left.cmp(&right)
+}
+
+fn vec_in_order(left: &[Value], right: &[Value]) -> Ordering {
+    trace!(left = left.len(), right = right.len(), "comparing lists");
+    let _out_of_order_by_length = left.len() <= right.len();
+    let temp_vec = [left.len(), right.len()];
+    let min_length = temp_vec.iter().min().unwrap();
+    for i in 0..*min_length {
+        let left_val = &left[i];
+        let right_val = &right[i];
+        let _nested_pair = Pair {
+            left: left_val.clone(),
+            right: right_val.clone(),
+        };
+        let in_order = value_in_order(left_val, right_val);
+        match in_order {
+            Ordering::Less => return Ordering::Less,
+            Ordering::Greater => return Ordering::Greater,
+            Ordering::Equal => {}
+        }
+    }
+
+    left.len().cmp(&right.len())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketError {
+    InvalidPacket,
+    MissingPacket,
+}
+
+impl fmt::Display for PacketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketError::InvalidPacket => write!(f, "packet is not a valid list"),
+            PacketError::MissingPacket => write!(f, "expected a pair of packets"),
+        }
+    }
+}
+
+fn parse_packet(input: &str, line: &str) -> Result<Value, ParseError<PacketError>> {
+    serde_json::from_str(line).map_err(|error| {
+        let offset = line
+            .char_indices()
+            .nth(error.column().saturating_sub(1))
+            .map_or(line.len(), |(offset, _)| offset);
+        let text = &line[offset..];
+        let text = &text[..text.chars().next().map_or(0, char::len_utf8)];
+        ParseError::at(PacketError::InvalidPacket, input, text)
+    })
+}
+
+fn parse_pairs(input: &str) -> Result<Vec<Pair>, ParseError<PacketError>> {
+    input
+        .split("\n\n")
+        .filter(|double_line| !double_line.trim().is_empty())
+        .map(|double_line| {
+            let mut lines = double_line.lines();
+            let mut packet = || {
+                let line = lines.next().ok_or_else(|| {
+                    ParseError::at(PacketError::MissingPacket, input, double_line)
+                })?;
+                parse_packet(input, line)
+            };
+            Ok(Pair {
+                left: packet()?,
+                right: packet()?,
+            })
+        })
+        .collect()
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError<PacketError>> {
+    let in_order: Vec<usize> = parse_pairs(input)?
+        .into_iter()
+        .enumerate()
+        .filter(|(index, pair)| {
+            let in_order = value_in_order(&pair.left, &pair.right);
+            trace!(pair = index + 1, ?in_order, "compared a pair");
+            in_order.is_lt()
+        })
+        .map(|tuple| tuple.0 + 1)
+        .collect();
+    debug!(?in_order, "found the pairs in the right order");
+    Ok(in_order.iter().sum())
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError<PacketError>> {
+    let mut values: Vec<Value> = parse_pairs(input)?
+        .into_iter()
+        .flat_map(|pair| [pair.left, pair.right])
+        .collect();
+    let two = json!([[2]]);
+    let six = json!([[6]]);
+    values.push(two.clone());
+    values.push(six.clone());
+
+    values.sort_by(value_in_order);
+    let product = values
+        .iter()
+        .enumerate()
+        .filter_map(|(i, val)| {
+            if *val == two || *val == six {
+                Some(i + 1)
+            } else {
+                None
+            }
+        })
+        .product();
+    Ok(product)
+
+    //    values
+    //        .iter()
+    //        .enumerate()
+    //        .filter(|(_, &list)| {
+    //            let key_pairs = list == Value::Array(Value::Array(Value::Number(2)))
+    //                || list == Value::Array(Value::Array(Value::Number(6)));
+    //            println!("---------------------------\n");
+    //            in_order.unwrap()
+    //        })
+    //        .map(|tuple| tuple.0 + 1)
+    //        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn given() {
+        let input = include_str!("../../inputs/day13/dev.txt");
+
+        assert_eq!(part_1(input).unwrap(), 13);
+        assert_eq!(part_2(input).unwrap(), 140);
+    }
+
+    #[test]
+    fn bad_packet_is_located() {
+        let error = part_1("[1,1]\n[1,2]\n\n[[1],[2,x]]\n[3]").unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(PacketError::InvalidPacket, 4, 9, "x")
+        );
+    }
+}
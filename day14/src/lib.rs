@@ -0,0 +1,337 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, Grid, ParseError, Point, Solution};
+use std::fmt;
+use tracing::debug;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+/// Rocks have to leave room for sand to fall around them and for the floor.
+const LANDSCAPE_SIZE: i64 = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RockError {
+    MissingComma,
+    NotANumber,
+    OutOfBounds,
+}
+
+impl fmt::Display for RockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RockError::MissingComma => write!(f, "expected a coordinate like 498,4"),
+            RockError::NotANumber => write!(f, "coordinate is not a number"),
+            RockError::OutOfBounds => write!(
+                f,
+                "coordinate must be within 1..{} across and 0..{} down",
+                LANDSCAPE_SIZE - 1,
+                LANDSCAPE_SIZE - 2
+            ),
+        }
+    }
+}
+
+fn parse_coordinate(s: &str) -> Result<Point, ParseError<RockError>> {
+    let coordinate = s.trim();
+    let (x, y) = coordinate
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(RockError::MissingComma, s, coordinate))?;
+    let number = |text: &str| {
+        let text = text.trim();
+        text.parse::<i64>()
+            .map_err(|_| ParseError::at(RockError::NotANumber, s, text))
+    };
+    let point = Point::new(number(x)?, number(y)?);
+    if !(1..LANDSCAPE_SIZE - 1).contains(&point.x) || !(0..LANDSCAPE_SIZE - 2).contains(&point.y) {
+        return Err(ParseError::at(RockError::OutOfBounds, s, coordinate));
+    }
+    Ok(point)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CellContents {
+    Air,
+    Rock,
+    Sand,
+}
+
+#[derive(Debug)]
+struct Landscape {
+    contents: Grid<CellContents>,
+}
+
+impl Landscape {
+    fn new(size: usize) -> Self {
+        Landscape {
+            contents: Grid::new(size, size, CellContents::Air),
+        }
+    }
+}
+
+pub fn part_2(input: &str) -> Result<usize> {
+    let mut landscape = Landscape::new(LANDSCAPE_SIZE as usize);
+    let rocks = parse_rocks(input).map_err(Diagnostic::from)?;
+    fill_landscape_with_rocks(&mut landscape, rocks);
+    let max_y_count = landscape
+        .contents
+        .iter()
+        .filter(|(_, &cell)| cell == CellContents::Rock)
+        .map(|(point, _)| point.y)
+        .max()
+        .unwrap_or(0);
+
+    debug!(max_y = max_y_count, "found the lowest rock");
+    let bottom_rock = vec![
+        Point::new(0, max_y_count + 2),
+        Point::new(LANDSCAPE_SIZE - 1, max_y_count + 2),
+    ];
+    fill_landscape_with_rocks(&mut landscape, vec![bottom_rock]);
+    let mut sand_full = false;
+    while !sand_full {
+        let mut sand_x = 500;
+        let mut sand_y = 0;
+        sand_full = landscape.contents[Point::new(sand_x, sand_y)] == CellContents::Sand;
+        let mut sand_resting = false;
+        while !sand_resting && !sand_full {
+            if landscape.contents[Point::new(sand_x, sand_y + 1)] == CellContents::Air {
+                sand_y += 1;
+            } else if landscape.contents[Point::new(sand_x - 1, sand_y + 1)] == CellContents::Air {
+                sand_y += 1;
+                sand_x -= 1;
+            } else if landscape.contents[Point::new(sand_x + 1, sand_y + 1)] == CellContents::Air {
+                sand_y += 1;
+                sand_x += 1;
+            } else {
+                landscape.contents[Point::new(sand_x, sand_y)] = CellContents::Sand;
+                sand_resting = true;
+            }
+        }
+    }
+    let sand_count = landscape
+        .contents
+        .iter()
+        .filter(|(_, cell)| matches!(cell, CellContents::Sand))
+        .count();
+    Ok(sand_count)
+}
+
+pub fn part_1(input: &str) -> Result<usize> {
+    let mut landscape = Landscape::new(LANDSCAPE_SIZE as usize);
+    let rocks = parse_rocks(input).map_err(Diagnostic::from)?;
+    fill_landscape_with_rocks(&mut landscape, rocks);
+
+    let mut sand_overflowing = false;
+    while !sand_overflowing {
+        let mut sand_x = 500;
+        let mut sand_y = 0;
+        let mut sand_resting = false;
+        while !sand_resting && !sand_overflowing {
+            if landscape.contents[Point::new(sand_x, sand_y + 1)] == CellContents::Air {
+                sand_y += 1;
+            } else if landscape.contents[Point::new(sand_x - 1, sand_y + 1)] == CellContents::Air {
+                sand_y += 1;
+                sand_x -= 1;
+            } else if landscape.contents[Point::new(sand_x + 1, sand_y + 1)] == CellContents::Air {
+                sand_y += 1;
+                sand_x += 1;
+            } else {
+                landscape.contents[Point::new(sand_x, sand_y)] = CellContents::Sand;
+                sand_resting = true;
+            }
+            if sand_y > LANDSCAPE_SIZE - 2 {
+                sand_overflowing = true;
+            }
+        }
+    }
+    let sand_count = landscape
+        .contents
+        .iter()
+        .filter(|(_, cell)| matches!(cell, CellContents::Sand))
+        .count();
+    Ok(sand_count)
+}
+
+fn parse_rocks(input: &str) -> Result<Vec<Vec<Point>>, ParseError<RockError>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split("->")
+                .map(|coord| parse_coordinate(coord).map_err(|error| error.within(input, coord)))
+                .collect()
+        })
+        .collect()
+}
+
+fn fill_landscape_with_rocks(landscape: &mut Landscape, rocks: Vec<Vec<Point>>) {
+    for rock in rocks {
+        rock.windows(2).for_each(|window| {
+            let first = window[0];
+            let second = window[1];
+            let x_range = second.x - first.x;
+            let y_range = second.y - first.y;
+            for x in first.x..=first.x + x_range {
+                landscape.contents[Point::new(x, first.y)] = CellContents::Rock;
+            }
+            for x in first.x + x_range..=first.x {
+                landscape.contents[Point::new(x, first.y)] = CellContents::Rock;
+            }
+            for y in first.y..=first.y + y_range {
+                landscape.contents[Point::new(first.x, y)] = CellContents::Rock;
+            }
+            for y in first.y + y_range..=first.y {
+                landscape.contents[Point::new(first.x, y)] = CellContents::Rock;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part1_given() {
+        let input = include_str!("../../inputs/day14/dev.txt");
+
+        let result = part_1(input).unwrap();
+
+        assert_eq!(result, 24);
+    }
+
+    #[test]
+    fn part2_given() {
+        let input = include_str!("../../inputs/day14/dev.txt");
+
+        let result = part_2(input).unwrap();
+
+        assert_eq!(result, 93);
+    }
+
+    #[test]
+    fn parse_rocks_one_line() {
+        let rocks_str = "498,4 -> 498,6 -> 496,6";
+
+        let rocks = parse_rocks(rocks_str).unwrap();
+
+        assert_eq!(
+            rocks,
+            vec![vec![
+                Point::new(498, 4),
+                Point::new(498, 6),
+                Point::new(496, 6)
+            ]]
+        );
+    }
+
+    #[test]
+    fn parse_rocks_longer() {
+        let rocks_str = "503,4 -> 502,4 -> 502,9 -> 494,9";
+
+        let rocks = parse_rocks(rocks_str).unwrap();
+
+        assert_eq!(
+            rocks,
+            vec![vec![
+                Point::new(503, 4),
+                Point::new(502, 4),
+                Point::new(502, 9),
+                Point::new(494, 9)
+            ]]
+        );
+    }
+
+    #[test]
+    fn parse_rocks_2_lines() {
+        let rocks_str = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+
+        let rocks = parse_rocks(rocks_str).unwrap();
+
+        assert_eq!(
+            rocks,
+            vec![
+                vec![Point::new(498, 4), Point::new(498, 6), Point::new(496, 6)],
+                vec![
+                    Point::new(503, 4),
+                    Point::new(502, 4),
+                    Point::new(502, 9),
+                    Point::new(494, 9)
+                ]
+            ]
+        );
+    }
+    #[test]
+    fn bad_coordinate_is_located() {
+        let rocks_str = "498,4 -> 498,6\n503,4 -> 502;4";
+
+        let error = parse_rocks(rocks_str).unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(RockError::MissingComma, 2, 10, "502;4")
+        );
+    }
+
+    #[test]
+    fn fill_rocks_1d() {
+        let rocks = vec![vec![Point::new(0, 0), Point::new(0, 5)]];
+        let mut landscape = Landscape::new(10);
+
+        fill_landscape_with_rocks(&mut landscape, rocks);
+        let rock_slice = &landscape
+            .contents
+            .column(0)
+            .take(6)
+            .copied()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rock_slice,
+            &[
+                CellContents::Rock,
+                CellContents::Rock,
+                CellContents::Rock,
+                CellContents::Rock,
+                CellContents::Rock,
+                CellContents::Rock
+            ]
+        );
+    }
+
+    #[test]
+    fn fill_rocks_2d() {
+        let rocks = vec![vec![Point::new(0, 0), Point::new(0, 5), Point::new(2, 5)]];
+        let mut landscape = Landscape::new(10);
+
+        fill_landscape_with_rocks(&mut landscape, rocks);
+        let vertical_rock_slice = &landscape
+            .contents
+            .column(0)
+            .take(6)
+            .copied()
+            .collect::<Vec<_>>();
+        let horizontal_rock_point_1 = &landscape.contents[Point::new(1, 5)];
+        let horizontal_rock_point_2 = &landscape.contents[Point::new(2, 5)];
+
+        assert_eq!(
+            vertical_rock_slice,
+            &[
+                CellContents::Rock,
+                CellContents::Rock,
+                CellContents::Rock,
+                CellContents::Rock,
+                CellContents::Rock,
+                CellContents::Rock
+            ]
+        );
+        assert_eq!(horizontal_rock_point_1, &CellContents::Rock);
+        assert_eq!(horizontal_rock_point_2, &CellContents::Rock);
+    }
+}
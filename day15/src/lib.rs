@@ -0,0 +1,322 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse_lines, Diagnostic, Interval, IntervalSet, ParseError, Point, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input, 2_000_000)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input, 4_000_000)?.to_string())
+    }
+}
+
+pub fn part_2(input: &str, max_value: i64) -> Result<i64> {
+    let sensor_data: Vec<SensorData> = parse_lines(input).map_err(Diagnostic::from)?;
+
+    let possibilites: Vec<Point> = sensor_data
+        .iter()
+        .flat_map(|sensor| sensor.circle_of_possibility())
+        .filter(|coord| {
+            coord.x >= 0 && coord.x <= max_value && coord.y >= 0 && coord.y <= max_value
+        })
+        .collect();
+
+    let place = possibilites
+        .iter()
+        .find(|coord| {
+            sensor_data
+                .iter()
+                .all(|sensor| !sensor.coordinate_not_beacon(coord))
+        })
+        .ok_or_else(|| anyhow!("every position is covered by a sensor"))?;
+
+    Ok(place.x * 4_000_000 + place.y)
+}
+
+pub fn part_1(input: &str, target_row: i64) -> Result<usize> {
+    let sensor_data: Vec<SensorData> = parse_lines(input).map_err(Diagnostic::from)?;
+
+    let covered: IntervalSet = sensor_data
+        .into_iter()
+        .flat_map(|sensor| sensor.non_beacon_spaces_range(target_row))
+        .collect();
+
+    Ok(covered.len() as usize)
+}
+
+/// Parses the `x=.., y=..` part of a sensor or beacon description.
+fn parse_coordinate(input: &str) -> Result<Point, ParseError<SensorError>> {
+    let missing = || ParseError::at(SensorError::MissingCoordinate, input, input.trim());
+    let (x_region, y_region) = input.split_once(',').ok_or_else(missing)?;
+
+    let (_x, x_str) = x_region.split_once('=').ok_or_else(missing)?;
+    let (_y, y_str) = y_region.split_once('=').ok_or_else(missing)?;
+    let number = |text: &str| {
+        text.parse()
+            .map_err(|_| ParseError::at(SensorError::NotANumber, input, text))
+    };
+
+    Ok(Point::new(number(x_str)?, number(y_str)?))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SensorError {
+    MissingColon,
+    MissingCoordinate,
+    NotANumber,
+}
+
+impl fmt::Display for SensorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SensorError::MissingColon => {
+                write!(f, "expected a sensor and a beacon separated by ':'")
+            }
+            SensorError::MissingCoordinate => write!(f, "expected a position like x=2, y=18"),
+            SensorError::NotANumber => write!(f, "coordinate is not a number"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+
+struct SensorData {
+    sensor: Point,
+    closest_beacon: Point,
+    manhattan_distance: i64,
+}
+
+impl FromStr for SensorData {
+    type Err = ParseError<SensorError>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (sensor, beacon) = input
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(SensorError::MissingColon, input, input))?;
+
+        Ok(SensorData::new(
+            parse_coordinate(sensor).map_err(|error| error.within(input, sensor))?,
+            parse_coordinate(beacon).map_err(|error| error.within(input, beacon))?,
+        ))
+    }
+}
+
+fn abs(value: i64) -> i64 {
+    if value > 0 {
+        value
+    } else {
+        -value
+    }
+}
+
+impl SensorData {
+    fn new(sensor: Point, closest_beacon: Point) -> Self {
+        let manhattan_distance = sensor.manhattan_distance(closest_beacon);
+        Self {
+            sensor,
+            closest_beacon,
+            manhattan_distance,
+        }
+    }
+
+    fn coordinate_not_beacon(&self, coord: &Point) -> bool {
+        self.sensor.manhattan_distance(*coord) <= self.manhattan_distance
+    }
+    fn circle_of_possibility(&self) -> Vec<Point> {
+        let distance = self.manhattan_distance + 1;
+        let mut vec = Vec::with_capacity((distance * 4) as usize);
+        // top left
+        for i in 0..distance {
+            vec.push(Point::new(
+                self.sensor.x - i,
+                self.sensor.y + (distance - i),
+            ))
+        }
+        // top right
+        for i in 0..distance {
+            vec.push(Point::new(
+                self.sensor.x + i,
+                self.sensor.y + (distance - i),
+            ))
+        }
+        // bottom right
+        for i in 0..distance {
+            vec.push(Point::new(
+                self.sensor.x - i,
+                self.sensor.y - (distance - i),
+            ))
+        }
+        // bottom left
+        for i in 0..distance {
+            vec.push(Point::new(
+                self.sensor.x + i,
+                self.sensor.y - (distance - i),
+            ))
+        }
+        vec
+    }
+
+    fn non_beacon_spaces_range(&self, target_row: i64) -> Option<Interval> {
+        let manhattan_distance = self.manhattan_distance;
+        let y_offset = abs(target_row - self.sensor.y);
+        if abs(y_offset) > manhattan_distance {
+            return None;
+        }
+
+        let x_offset = manhattan_distance - y_offset;
+
+        let mut lower = self.sensor.x - x_offset;
+        let mut upper = self.sensor.x + x_offset;
+
+        if self.closest_beacon.y == target_row {
+            if self.closest_beacon.x < self.sensor.x {
+                lower += 1;
+            } else if self.closest_beacon.x > self.sensor.x {
+                upper -= 1;
+            } else {
+                return None;
+            }
+        }
+
+        Interval::new(lower, upper)
+    }
+    #[allow(dead_code)]
+    fn non_beacon_spaces(&self) -> HashSet<Point> {
+        let non_beacon_spaces: HashSet<Point> = (-self.manhattan_distance
+            ..=self.manhattan_distance)
+            .flat_map(|x_offset| {
+                let y_offset = self.manhattan_distance - abs(x_offset);
+                (-y_offset..=y_offset)
+                    .map(move |y| Point::new(self.sensor.x + x_offset, self.sensor.y + y))
+            })
+            .collect();
+
+        non_beacon_spaces
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day15/dev.txt");
+
+        let output = part_1(input, 10).unwrap();
+
+        assert_eq!(output, 26);
+    }
+
+    #[test]
+    fn part_2_given() {
+        let input = include_str!("../../inputs/day15/dev.txt");
+
+        let output = part_2(input, 20).unwrap();
+
+        assert_eq!(output, 56000011);
+    }
+
+    #[test]
+    fn parse_coordinate_sensor() {
+        let input = "Sensor at x=2, y=18";
+        let expected_coordinate = Point::new(2, 18);
+
+        let actual_coordinate = parse_coordinate(input).unwrap();
+
+        assert_eq!(expected_coordinate, actual_coordinate);
+    }
+
+    #[test]
+
+    fn parse_coordinate_beacon() {
+        let input = "closest beacon is at x=-2, y=15";
+
+        let expected_coordinate = Point::new(-2, 15);
+
+        let actual_coordinate = parse_coordinate(input).unwrap();
+
+        assert_eq!(expected_coordinate, actual_coordinate);
+    }
+    #[test]
+
+    fn parse_sensor_data() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+
+        let expected_sensor = SensorData::new(Point::new(2, 18), Point::new(-2, 15));
+        let actual_sensor: SensorData = input.parse().unwrap();
+        assert_eq!(expected_sensor, actual_sensor);
+    }
+
+    #[test]
+    fn bad_sensor_is_located() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=1b: closest beacon is at x=10, y=16";
+
+        let error = parse_lines::<SensorData, _>(input).unwrap_err();
+
+        assert_eq!(error, ParseError::new(SensorError::NotANumber, 2, 18, "1b"));
+    }
+
+    #[test]
+
+    fn find_blocked_spaces() {
+        let sensor_data = SensorData::new(Point::new(0, 0), Point::new(1, 0));
+
+        let mut expected_blocked_coordinates: HashSet<Point> = HashSet::new();
+
+        expected_blocked_coordinates.insert(Point::new(-1, 0));
+        expected_blocked_coordinates.insert(Point::new(0, 0));
+        expected_blocked_coordinates.insert(Point::new(0, -1));
+        expected_blocked_coordinates.insert(Point::new(0, 1));
+        expected_blocked_coordinates.insert(Point::new(1, 0));
+
+        let actual_blocked_coordinates = sensor_data.non_beacon_spaces();
+        assert_eq!(expected_blocked_coordinates, actual_blocked_coordinates);
+    }
+
+    #[test]
+    fn range_upper_beacon() {
+        let sensor_data = SensorData::new(Point::new(0, 0), Point::new(2, 3));
+        let expected_range = Interval::new(-2, 1).unwrap();
+
+        let range = sensor_data.non_beacon_spaces_range(3).unwrap();
+
+        assert_eq!(expected_range, range);
+    }
+
+    #[test]
+    fn range_lower_beacon() {
+        let sensor_data = SensorData::new(Point::new(0, 0), Point::new(-2, 3));
+        let expected_range = Interval::new(-1, 2).unwrap();
+
+        let range = sensor_data.non_beacon_spaces_range(3).unwrap();
+
+        assert_eq!(expected_range, range);
+    }
+
+    #[test]
+    fn range_no_conflict() {
+        let sensor_data = SensorData::new(Point::new(0, 0), Point::new(-2, 3));
+        let expected_range = Interval::new(-3, 3).unwrap();
+
+        let range = sensor_data.non_beacon_spaces_range(2).unwrap();
+
+        assert_eq!(expected_range, range);
+    }
+
+    #[test]
+    fn range_given() {
+        let sensor_data = SensorData::new(Point::new(8, 7), Point::new(2, 10));
+        let expected_range = Interval::new(3, 14).unwrap();
+
+        let range = sensor_data.non_beacon_spaces_range(10).unwrap();
+
+        assert_eq!(expected_range, range);
+    }
+}
@@ -0,0 +1,269 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{Diagnostic, Grid, ParseError, Point, Solution};
+use std::fmt;
+use tracing::debug;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve(input, 2022).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, _input: &str) -> Result<String> {
+        Err(anyhow!("day 17 part 2 has not been solved yet"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Space {
+    Empty,
+    Full,
+}
+
+enum Push {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JetError {
+    NotAJet,
+    NoJets,
+}
+
+impl fmt::Display for JetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JetError::NotAJet => write!(f, "jets should be '<' or '>'"),
+            JetError::NoJets => write!(f, "expected a line of jets"),
+        }
+    }
+}
+
+impl TryFrom<char> for Push {
+    type Error = JetError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '<' => Ok(Self::Left),
+            '>' => Ok(Self::Right),
+            _ => Err(JetError::NotAJet),
+        }
+    }
+}
+
+fn parse_pushes(input: &str) -> Result<Vec<Push>, ParseError<JetError>> {
+    let jets = input.trim_end();
+    if jets.is_empty() {
+        return Err(ParseError::at(JetError::NoJets, input, jets));
+    }
+    jets.char_indices()
+        .map(|(offset, c)| {
+            Push::try_from(c)
+                .map_err(|kind| ParseError::at(kind, input, &jets[offset..offset + c.len_utf8()]))
+        })
+        .collect()
+}
+
+struct Chamber {
+    spaces: Grid<Space>,
+}
+
+impl Chamber {
+    fn new() -> Self {
+        // Only the top of the tower matters, so rows are reused once y wraps.
+        let height = 100_000;
+        let mut spaces = Grid::new(9, height as usize, Space::Empty);
+        for x in 0..9 {
+            spaces[Point::new(x, 0)] = Space::Full;
+        }
+        for y in 0..height {
+            spaces[Point::new(0, y)] = Space::Full;
+            spaces[Point::new(8, y)] = Space::Full;
+        }
+        Self { spaces }
+    }
+    fn set_space(&mut self, y: i64, x: i64, space: Space) {
+        let point = self.spaces.wrap(Point::new(x, y));
+        self.spaces[point] = space;
+    }
+    fn get_space(&self, y: i64, x: i64) -> Space {
+        self.spaces[self.spaces.wrap(Point::new(x, y))]
+    }
+    fn clear_rows_above(&mut self, height: i64) {
+        for i in 1..10 {
+            for x in 1..=7 {
+                self.set_space(height + i, x, Space::Empty);
+            }
+        }
+    }
+    #[allow(dead_code)]
+    fn print_first_10(&self) {
+        let first_10: Vec<&[Space]> = self.spaces.rows().take(10).collect();
+        debug!(?first_10, "top of the chamber");
+    }
+    fn max_height(&self) -> i64 {
+        self.spaces
+            .rows()
+            .position(|row| row[1..=7].iter().all(|space| matches!(space, Space::Empty)))
+            .expect("At least one run must have all empty spaces")
+            .try_into()
+            .unwrap()
+    }
+    fn place_piece(&mut self, piece: &[Point], coord: &Point) {
+        for part in piece.iter() {
+            let absolute_x = coord.x + part.x;
+            let absolute_y = coord.y + part.y;
+            self.set_space(absolute_y, absolute_x, Space::Full);
+        }
+
+        let tower_max = self.max_height();
+
+        self.clear_rows_above(coord.y + tower_max);
+    }
+
+    fn any_pieces_below(&self, piece: &[Point], coord: &Point) -> bool {
+        piece.iter().any(|relative| {
+            let x = coord.x + relative.x;
+            let y = (coord.y + relative.y) - 1;
+            match self.get_space(y, x) {
+                Space::Full => true,
+                Space::Empty => false,
+            }
+        })
+    }
+
+    fn any_pieces_to_left(&self, piece: &[Point], coord: &Point) -> bool {
+        piece.iter().any(|relative| {
+            let x = coord.x + relative.x - 1;
+            let y = coord.y + relative.y;
+            match self.get_space(y, x) {
+                Space::Full => true,
+                Space::Empty => false,
+            }
+        })
+    }
+
+    fn any_pieces_to_right(&self, piece: &[Point], coord: &Point) -> bool {
+        piece.iter().any(|relative| {
+            let x = coord.x + relative.x + 1;
+            let y = coord.y + relative.y;
+            match self.get_space(y, x) {
+                Space::Full => true,
+                Space::Empty => false,
+            }
+        })
+    }
+
+    fn push_piece(&self, piece: &[Point], coord: Point, push: &Push) -> Point {
+        match push {
+            Push::Left => {
+                if self.any_pieces_to_left(piece, &coord) {
+                    coord
+                } else {
+                    Point::new(coord.x - 1, coord.y)
+                }
+            }
+            Push::Right => {
+                if self.any_pieces_to_right(piece, &coord) {
+                    coord
+                } else {
+                    Point::new(coord.x + 1, coord.y)
+                }
+            }
+        }
+    }
+}
+
+fn solve(input: &str, piece_count: usize) -> Result<i64, ParseError<JetError>> {
+    let pushes = parse_pushes(input)?;
+    let mut chamber = Chamber::new();
+    let minus = vec![
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(3, 0),
+    ];
+
+    let plus = vec![
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(2, 1),
+        Point::new(1, 2),
+    ];
+
+    let l = vec![
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(2, 1),
+        Point::new(2, 2),
+    ];
+
+    let vertical = vec![
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(0, 2),
+        Point::new(0, 3),
+    ];
+
+    let square = vec![
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(1, 0),
+        Point::new(1, 1),
+    ];
+
+    let pieces = [minus, plus, l, vertical, square];
+    let mut pushes_count = 0;
+    for num in 0..piece_count {
+        let starting_height = chamber.max_height() + 3;
+        let mut coord = Point::new(3, starting_height);
+
+        let piece = &pieces[num % pieces.len()];
+        loop {
+            let push = &pushes[pushes_count % pushes.len()];
+            pushes_count += 1;
+            coord = chamber.push_piece(piece, coord, push);
+
+            // time to try to push
+            if chamber.any_pieces_below(piece, &coord) {
+                break;
+            } else {
+                coord.y -= 1;
+            }
+        }
+        chamber.place_piece(piece, &coord);
+    }
+    Ok(chamber.max_height() - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day17/dev.txt");
+
+        let output = solve(input, 2022).unwrap();
+
+        assert_eq!(output, 3068);
+    }
+
+    #[test]
+    #[ignore]
+    fn part_2_given() {
+        let input = include_str!("../../inputs/day17/dev.txt");
+        let output = solve(input, 1_000_000_000_000).unwrap();
+
+        assert_eq!(output, 1514285714288);
+    }
+
+    #[test]
+    fn bad_jet_is_located() {
+        let error = solve("<<>x>", 1).unwrap_err();
+
+        assert_eq!(error, ParseError::new(JetError::NotAJet, 1, 4, "x"));
+    }
+}
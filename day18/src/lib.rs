@@ -0,0 +1,182 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Point3, Solution};
+use std::fmt;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError<CubeError>> {
+    let cubes = parse_cubes(input)?;
+    Ok(surface_area(&cubes) - interior_surface_area(&cubes))
+}
+
+/// Counts the faces that touch trapped air pockets rather than the outside.
+fn interior_surface_area(blocks: &[Point3]) -> usize {
+    let max_x = blocks.iter().map(|b| b.x).max().unwrap_or(0);
+    let max_y = blocks.iter().map(|b| b.y).max().unwrap_or(0);
+    let max_z = blocks.iter().map(|b| b.z).max().unwrap_or(0);
+    let mut space = Space::new(
+        (max_x + 1) as usize,
+        (max_y + 1) as usize,
+        (max_z + 1) as usize,
+    );
+    space.add_blocks(blocks);
+    space.flood();
+    let air_spaces: Vec<Point3> = space
+        .all
+        .iter()
+        .enumerate()
+        .flat_map(move |(x, yz)| {
+            yz.iter().enumerate().flat_map(move |(y, z_vec)| {
+                z_vec
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, point)| matches!(point, Point::Air))
+                    .map(move |(z, _)| Point3::new(x as i64, y as i64, z as i64))
+            })
+        })
+        .collect();
+    let mut exposed_edges = air_spaces.len() * 6;
+    air_spaces.iter().for_each(|cube| {
+        let other_cubes = air_spaces.iter().filter(|&other| other != cube);
+        let covered_sides = other_cubes.filter(|other| faces_touch(cube, other)).count();
+        exposed_edges -= covered_sides;
+    });
+    exposed_edges
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError<CubeError>> {
+    Ok(surface_area(&parse_cubes(input)?))
+}
+
+fn surface_area(cubes: &[Point3]) -> usize {
+    let mut exposed_edges = cubes.len() * 6;
+    cubes.iter().for_each(|cube| {
+        let other_cubes = cubes.iter().filter(|&other| other != cube);
+        let covered_sides = other_cubes.filter(|other| faces_touch(cube, other)).count();
+        exposed_edges -= covered_sides;
+    });
+    exposed_edges
+}
+struct Space {
+    all: Vec<Vec<Vec<Point>>>,
+}
+
+impl Space {
+    fn new(x: usize, y: usize, z: usize) -> Self {
+        Self {
+            all: vec![vec![vec![Point::Air; z]; y]; x],
+        }
+    }
+    fn x_length(&self) -> i64 {
+        self.all.len() as i64
+    }
+    fn y_length(&self) -> i64 {
+        self.all[0].len() as i64
+    }
+    fn z_length(&self) -> i64 {
+        self.all[0][0].len() as i64
+    }
+    fn add_blocks(&mut self, blocks: &[Point3]) {
+        for block in blocks.iter() {
+            self.all[block.x as usize][block.y as usize][block.z as usize] = Point::Block;
+        }
+    }
+    fn flood(&mut self) {
+        let mut coordinates = vec![Point3::ORIGIN];
+        while let Some(c) = coordinates.pop() {
+            if c.x < 0
+                || c.y < 0
+                || c.z < 0
+                || c.x == self.x_length()
+                || c.y == self.y_length()
+                || c.z == self.z_length()
+            {
+                continue;
+            }
+            let point = &self.all[c.x as usize][c.y as usize][c.z as usize];
+            if let Point::Air = point {
+                self.all[c.x as usize][c.y as usize][c.z as usize] = Point::Water;
+                coordinates.extend(c.neighbours6());
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Point {
+    Water,
+    Block,
+    Air,
+}
+
+fn faces_touch(cube: &Point3, other: &Point3) -> bool {
+    cube.manhattan_distance(*other) == 1
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CubeError {
+    WrongCoordinateCount,
+    NotACoordinate,
+}
+
+impl fmt::Display for CubeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CubeError::WrongCoordinateCount => write!(f, "expected three coordinates like 2,2,2"),
+            CubeError::NotACoordinate => write!(f, "coordinate is not a whole number of 0 or more"),
+        }
+    }
+}
+
+fn parse_cube(s: &str) -> Result<Point3, ParseError<CubeError>> {
+    let coords: Vec<&str> = s.split(',').collect();
+    let [x, y, z] = coords[..] else {
+        return Err(ParseError::at(CubeError::WrongCoordinateCount, s, s));
+    };
+    let coordinate = |text: &str| {
+        text.parse::<u32>()
+            .map(i64::from)
+            .map_err(|_| ParseError::at(CubeError::NotACoordinate, s, text))
+    };
+    Ok(Point3::new(coordinate(x)?, coordinate(y)?, coordinate(z)?))
+}
+
+fn parse_cubes(input: &str) -> Result<Vec<Point3>, ParseError<CubeError>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_cube(line).map_err(|error| error.offset_lines(index)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn given() {
+        let input = include_str!("../../inputs/day18/dev.txt");
+
+        assert_eq!(part_1(input).unwrap(), 64);
+        assert_eq!(part_2(input).unwrap(), 58);
+    }
+
+    #[test]
+    fn bad_cube_is_located() {
+        let error = part_1("2,2,2\n1,-2,2").unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(CubeError::NotACoordinate, 2, 3, "-2")
+        );
+    }
+}
@@ -1,17 +1,21 @@
-use regex::Regex;
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::ops::Sub;
 use std::str::FromStr;
-use std::time::Instant;
-
-fn main() {
-    println!("Hello, world!");
-    let input = include_str!("../input.dev");
-    let start = Instant::now();
-    let output = part_2(input);
-    let elapsed = Instant::now() - start;
-    println!("Output is {:?}", output);
-    println!("Took {:?} to run", elapsed);
+
+use anyhow::Result;
+use aoc_common::{parse_lines, Diagnostic, ParseError, Solution};
+use tracing::{debug, trace};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
 }
 
 #[derive(Debug, PartialEq, PartialOrd, Copy, Clone)]
@@ -25,8 +29,8 @@ struct Blueprint {
 
 impl Blueprint {
     fn max_cost(&self) -> Resources {
-        return Resources {
-            ore: *vec![
+        Resources {
+            ore: *[
                 self.ore_robot_cost.ore,
                 self.clay_robot_cost.ore,
                 self.obsidian_robot_cost.ore,
@@ -35,7 +39,7 @@ impl Blueprint {
             .iter()
             .max()
             .unwrap(),
-            clay: *vec![
+            clay: *[
                 self.ore_robot_cost.clay,
                 self.clay_robot_cost.clay,
                 self.obsidian_robot_cost.clay,
@@ -44,7 +48,7 @@ impl Blueprint {
             .iter()
             .max()
             .unwrap(),
-            obsidian: *vec![
+            obsidian: *[
                 self.ore_robot_cost.obsidian,
                 self.clay_robot_cost.obsidian,
                 self.obsidian_robot_cost.obsidian,
@@ -54,41 +58,86 @@ impl Blueprint {
             .max()
             .unwrap(),
             geode: 0,
-        };
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlueprintError {
+    Expected(&'static str),
+    NotANumber,
+}
+
+impl fmt::Display for BlueprintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlueprintError::Expected(text) => write!(f, "expected {:?}", text.trim()),
+            BlueprintError::NotANumber => write!(f, "expected a number"),
+        }
     }
 }
 
+/// The text around each number in a blueprint, in order.
+const BLUEPRINT_TEXT: [&str; 7] = [
+    "Blueprint ",
+    ": Each ore robot costs ",
+    " ore. Each clay robot costs ",
+    " ore. Each obsidian robot costs ",
+    " ore and ",
+    " clay. Each geode robot costs ",
+    " ore and ",
+];
+
 impl FromStr for Blueprint {
-    type Err = anyhow::Error;
+    type Err = ParseError<BlueprintError>;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let blueprint_regex: Regex = Regex::new(
-            r"Blueprint (\d*): Each ore robot costs (\d*) ore\. Each clay robot costs (\d*) ore\. Each obsidian robot costs (\d*) ore and (\d*) clay\. Each geode robot costs (\d*) ore and (\d*) obsidian\."
-        ).unwrap();
-        let captures = blueprint_regex.captures(s).unwrap();
-        let id = captures[1].parse().unwrap();
-        let ore_robot_ore_cost = captures[2].parse().unwrap();
-        let clay_robot_ore_cost = captures[3].parse().unwrap();
-        let obsidian_robot_ore_cost = captures[4].parse().unwrap();
-        let obsidian_robot_clay_cost = captures[5].parse().unwrap();
-        let geode_robot_ore_cost = captures[6].parse().unwrap();
-        let geode_robot_obisidan_cost = captures[7].parse().unwrap();
+        let mut rest = s;
+        let mut numbers = [0; 7];
+        for (text, number) in BLUEPRINT_TEXT.into_iter().zip(numbers.iter_mut()) {
+            rest = rest.strip_prefix(text).ok_or_else(|| {
+                ParseError::at(BlueprintError::Expected(text), s, next_word(rest))
+            })?;
+            let digits = &rest[..rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len())];
+            *number = digits
+                .parse()
+                .map_err(|_| ParseError::at(BlueprintError::NotANumber, s, next_word(rest)))?;
+            rest = &rest[digits.len()..];
+        }
+        if !rest.starts_with(" obsidian.") {
+            return Err(ParseError::at(
+                BlueprintError::Expected(" obsidian."),
+                s,
+                next_word(rest),
+            ));
+        }
+        let [id, ore_robot_ore_cost, clay_robot_ore_cost, obsidian_robot_ore_cost, obsidian_robot_clay_cost, geode_robot_ore_cost, geode_robot_obisidan_cost] =
+            numbers;
         let ore_robot_cost = Resources::new(ore_robot_ore_cost, 0, 0, 0);
         let clay_robot_cost = Resources::new(clay_robot_ore_cost, 0, 0, 0);
         let obsidian_robot_cost =
             Resources::new(obsidian_robot_ore_cost, obsidian_robot_clay_cost, 0, 0);
         let geode_robot_cost =
             Resources::new(geode_robot_ore_cost, 0, geode_robot_obisidan_cost, 0);
-        return Ok(Blueprint {
+        Ok(Blueprint {
             id,
             ore_robot_cost,
             clay_robot_cost,
             obsidian_robot_cost,
             geode_robot_cost,
-        });
+        })
     }
 }
 
+/// The word at the start of `rest`, to point at when the blueprint goes wrong.
+fn next_word(rest: &str) -> &str {
+    let rest = rest.trim_start();
+    &rest[..rest.find(' ').unwrap_or(rest.len())]
+}
+
 #[derive(Debug, PartialEq, PartialOrd, Copy, Clone, Hash, Eq)]
+#[allow(dead_code)]
 struct RobotCount {
     ore: usize,
     clay: usize,
@@ -118,18 +167,18 @@ impl Sub for Resources {
 
 impl Resources {
     fn greater_or_equal_than(&self, other: &Self) -> bool {
-        return self.ore >= other.ore
+        self.ore >= other.ore
             && self.clay >= other.clay
             && self.obsidian >= other.obsidian
-            && self.geode >= other.geode;
+            && self.geode >= other.geode
     }
     fn new(ore: usize, clay: usize, obsidian: usize, geode: usize) -> Self {
-        return Self {
+        Self {
             ore,
             clay,
             obsidian,
             geode,
-        };
+        }
     }
 }
 
@@ -142,7 +191,7 @@ struct GameState {
 
 impl GameState {
     fn new() -> Self {
-        return Self {
+        Self {
             move_number: 0,
             resources: Resources {
                 ore: 0,
@@ -156,24 +205,26 @@ impl GameState {
                 obsidian: 0,
                 geode: 0,
             },
-        };
+        }
     }
+    #[allow(dead_code)]
     fn is_objectively_better_than(&self, other: &Self) -> bool {
         self.move_number <= other.move_number
             && self.resources.greater_or_equal_than(&other.resources)
             && self.robot_count.greater_or_equal_than(&other.robot_count)
     }
+    #[allow(dead_code)]
     fn score(&self, blueprint: &Blueprint, max_move_count: usize) -> u64 {
-        return (max_move_count - self.move_number) as u64
+        (max_move_count - self.move_number) as u64
             * (self.robot_count.geode * 1000
                 + self.robot_count.obsidian * 1000 / blueprint.geode_robot_cost.obsidian)
-                as u64;
+                as u64
     }
     fn collect_resources(&mut self) {
-        self.resources.ore = self.resources.ore + self.robot_count.ore;
-        self.resources.clay = self.resources.clay + self.robot_count.clay;
-        self.resources.obsidian = self.resources.obsidian + self.robot_count.obsidian;
-        self.resources.geode = self.resources.geode + self.robot_count.geode;
+        self.resources.ore += self.robot_count.ore;
+        self.resources.clay += self.robot_count.clay;
+        self.resources.obsidian += self.robot_count.obsidian;
+        self.resources.geode += self.robot_count.geode;
     }
     fn build_ore_robot_if_possible(mut self, blueprint: &Blueprint) -> Option<Self> {
         if !self
@@ -188,8 +239,8 @@ impl GameState {
         }
         self.resources = self.resources - blueprint.ore_robot_cost;
         self.collect_resources();
-        self.robot_count.ore = self.robot_count.ore + 1;
-        return Some(self);
+        self.robot_count.ore += 1;
+        Some(self)
     }
     fn build_clay_robot_if_possible(mut self, blueprint: &Blueprint) -> Option<Self> {
         if !self
@@ -204,8 +255,8 @@ impl GameState {
         }
         self.resources = self.resources - blueprint.clay_robot_cost;
         self.collect_resources();
-        self.robot_count.clay = self.robot_count.clay + 1;
-        return Some(self);
+        self.robot_count.clay += 1;
+        Some(self)
     }
     fn build_obsidian_robot_if_possible(mut self, blueprint: &Blueprint) -> Option<Self> {
         if !self
@@ -220,8 +271,8 @@ impl GameState {
         }
         self.resources = self.resources - blueprint.obsidian_robot_cost;
         self.collect_resources();
-        self.robot_count.obsidian = self.robot_count.obsidian + 1;
-        return Some(self);
+        self.robot_count.obsidian += 1;
+        Some(self)
     }
     fn build_geode_robot_if_possible(mut self, blueprint: &Blueprint) -> Option<Self> {
         if !self
@@ -232,14 +283,14 @@ impl GameState {
         }
         self.resources = self.resources - blueprint.geode_robot_cost;
         self.collect_resources();
-        self.robot_count.geode = self.robot_count.geode + 1;
-        return Some(self);
+        self.robot_count.geode += 1;
+        Some(self)
     }
     fn can_buy_any_robot(&self, blueprint: &Blueprint) -> bool {
         self.resources.greater_or_equal_than(&blueprint.max_cost())
     }
     fn generate_all_next_moves(mut self, blueprint: &Blueprint) -> Vec<Self> {
-        self.move_number = self.move_number + 1;
+        self.move_number += 1;
         let mut possible_moves = Vec::new();
         if !self.can_buy_any_robot(blueprint) || self.moves_left(MAX_MOVE_COUNT) == 0 {
             let mut do_nothing = self.clone();
@@ -255,7 +306,7 @@ impl GameState {
             possible_moves.push(self.clone().build_ore_robot_if_possible(blueprint));
         }
         let next_moves: Vec<Self> = possible_moves.into_iter().flatten().collect();
-        return next_moves;
+        next_moves
     }
     fn moves_left(&self, max_move_count: usize) -> usize {
         max_move_count - self.move_number
@@ -264,13 +315,14 @@ impl GameState {
         let moves_left = self.moves_left(max_move_count);
         let base_production = self.robot_count.geode * moves_left;
         let staircase_production: usize = (1..=moves_left).sum();
-        return self.resources.geode + base_production + staircase_production;
+        self.resources.geode + base_production + staircase_production
     }
 }
 
 const MAX_MOVE_COUNT: usize = 32;
 
 struct GameStateValidator {
+    #[allow(dead_code)]
     states_by_move: HashMap<usize, Vec<GameState>>,
 }
 
@@ -280,6 +332,7 @@ impl GameStateValidator {
             states_by_move: HashMap::new(),
         }
     }
+    #[allow(dead_code)]
     fn sequence_has_merit(&mut self, state: &GameState) -> bool {
         match self.states_by_move.get_mut(&state.move_number) {
             Some(vec) => {
@@ -316,11 +369,11 @@ struct Game {
 impl Game {
     fn new(blueprint: Blueprint, max_move_count: usize) -> Self {
         let game_state = GameState::new();
-        return Self {
+        Self {
             blueprint,
             max_move_count,
             states: vec![game_state],
-        };
+        }
     }
     fn get_quality_level(&mut self) -> usize {
         self.get_max_geodes() * self.blueprint.id
@@ -333,14 +386,14 @@ impl Game {
         let mut highest_geode_state = GameState::new();
         let mut skipped_for_max_geode = 0;
 
-        let mut state_validator = GameStateValidator::new();
-        let mut skipped_from_validator = 0;
+        let _state_validator = GameStateValidator::new();
+        let skipped_from_validator = 0;
 
         while let Some(state) = self.states.pop() {
-            considered_count = considered_count + 1;
+            considered_count += 1;
             if state.move_number == self.max_move_count {
                 if state.resources.geode > highest_geode_count {
-                    println!("Reset highest geode count to {:?}", state.resources.geode);
+                    debug!(geodes = state.resources.geode, "found a better plan");
                     highest_geode_count = state.resources.geode;
                     highest_geode_state = state;
                 }
@@ -348,13 +401,12 @@ impl Game {
             }
             for next_move in state.generate_all_next_moves(&self.blueprint).into_iter() {
                 if considered_states.contains(&next_move) {
-                    skipped_for_considered_states_before_add =
-                        skipped_for_considered_states_before_add + 1;
+                    skipped_for_considered_states_before_add += 1;
                     continue;
                 } else if next_move.maximum_possible_geode_at_end(MAX_MOVE_COUNT)
                     < highest_geode_count
                 {
-                    skipped_for_max_geode = skipped_for_max_geode + 1;
+                    skipped_for_max_geode += 1;
 
                     //                } else if !state_validator.sequence_has_merit(&next_move) {
                     //                    skipped_from_validator = skipped_from_validator + 1;
@@ -366,43 +418,39 @@ impl Game {
             }
         }
 
-        println!("Best state is {:#?}", highest_geode_state);
-        println!("For blueprint {:#?}", self.blueprint);
-        println!("We looked at  {:?}", considered_count);
-        println!(
-            "We skipped {:?} because a duplicate had been considered before this one",
-            skipped_for_considered_states_before_add
-        );
-        println!("We skipped {:?} from the validator", skipped_from_validator);
-        println!(
-            "We skipped {:?} from the max lookahead",
-            skipped_for_max_geode
+        trace!(?highest_geode_state, "best plan");
+        debug!(
+            blueprint = self.blueprint.id,
+            considered = considered_count,
+            skipped_duplicates = skipped_for_considered_states_before_add,
+            skipped_from_validator,
+            skipped_from_lookahead = skipped_for_max_geode,
+            geodes = highest_geode_count,
+            "searched blueprint"
         );
-        println!("Most geodes are {:?}", highest_geode_count);
-        return highest_geode_count;
+        highest_geode_count
     }
 }
 
-fn part_1(input: &str) -> usize {
-    let blueprints: Vec<Blueprint> = input.lines().map(|line| line.parse().unwrap()).collect();
-    let total_quality = blueprints
+pub fn part_1(input: &str) -> Result<usize, ParseError<BlueprintError>> {
+    let blueprints: Vec<Blueprint> = parse_lines(input)?;
+
+    Ok(blueprints
         .into_iter()
         .map(|blueprint| Game::new(blueprint, MAX_MOVE_COUNT))
         .map(|mut game| game.get_quality_level())
-        .sum();
-
-    return total_quality;
+        .sum())
 }
 
-fn part_2(input: &str) -> usize {
-    let blueprints: Vec<Blueprint> = input.lines().map(|line| line.parse().unwrap()).collect();
+pub fn part_2(input: &str) -> Result<usize, ParseError<BlueprintError>> {
+    let blueprints: Vec<Blueprint> = parse_lines(input)?;
     let total_quality = blueprints[..]
-        .into_iter()
+        .iter()
         .map(|blueprint| Game::new(*blueprint, 32))
         .map(|mut game| game.get_max_geodes())
         .product();
 
-    return total_quality;
+    Ok(total_quality)
 }
 
 #[cfg(test)]
@@ -441,7 +489,7 @@ mod test {
 
     //    #[test]
     //    fn part_1_given() {
-    //        let input = include_str!("../input.dev");
+    //        let input = include_str!("../../inputs/day19/dev.txt");
     //
     //        let output = part_1(input);
     //
@@ -493,8 +541,23 @@ mod test {
 
         assert_eq!(expected_blueprint, blueprint);
     }
+
+    #[test]
+    fn bad_blueprint_is_located() {
+        let blueprint_str =
+            "Blueprint 30: Each ore robot costs 4 ore. Each clay robot costs three ore.";
+
+        let error = blueprint_str.parse::<Blueprint>().unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(BlueprintError::NotANumber, 1, 65, "three")
+        );
+    }
+
+    #[allow(dead_code)]
     fn generate_1_cost_blueprint() -> Blueprint {
-        return Blueprint {
+        Blueprint {
             id: 1,
             ore_robot_cost: Resources {
                 ore: 1,
@@ -520,6 +583,6 @@ mod test {
                 obsidian: 1,
                 geode: 0,
             },
-        };
+        }
     }
 }
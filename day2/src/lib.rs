@@ -0,0 +1,278 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use std::fmt;
+
+mod rules;
+mod solver;
+mod tournament;
+pub use rules::*;
+pub use solver::*;
+pub use tournament::*;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+pub fn part_1(input: &str) -> Result<GameScore, ParseError<StrategyError>> {
+    score_guide(input, &RuleSet::rock_paper_scissors(), Decoding::Literal)
+}
+
+pub fn part_2(input: &str) -> Result<GameScore, ParseError<StrategyError>> {
+    score_guide(input, &RuleSet::rock_paper_scissors(), Decoding::Strategic)
+}
+
+/// How to read the second column of a strategy guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Decoding {
+    /// The move we play.
+    Literal,
+    /// The outcome we should get.
+    Strategic,
+}
+
+/// The total score from following a strategy guide.
+pub fn score_guide(
+    input: &str,
+    rules: &RuleSet,
+    decoding: Decoding,
+) -> Result<GameScore, ParseError<StrategyError>> {
+    Ok(parse_guide(input, rules, decoding)?
+        .iter()
+        .map(|game| game.score(rules))
+        .sum())
+}
+
+pub fn parse_guide(
+    input: &str,
+    rules: &RuleSet,
+    decoding: Decoding,
+) -> Result<Vec<Game>, ParseError<StrategyError>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            Game::decode(line, rules, decoding).map_err(|error| error.offset_lines(index))
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StrategyError {
+    MissingColumn,
+    UnknownOpponentMove,
+    UnknownResponse,
+}
+
+impl fmt::Display for StrategyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrategyError::MissingColumn => write!(f, "expected two columns separated by a space"),
+            StrategyError::UnknownOpponentMove => {
+                write!(f, "opponent move is not one this game knows")
+            }
+            StrategyError::UnknownResponse => write!(f, "response is not one this game knows"),
+        }
+    }
+}
+
+/// One round of a strategy guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub our: Move,
+    pub opponent: Move,
+}
+
+impl Game {
+    /// Reads one line of a strategy guide, such as `A Y`.
+    pub fn decode(
+        line: &str,
+        rules: &RuleSet,
+        decoding: Decoding,
+    ) -> Result<Self, ParseError<StrategyError>> {
+        let (opponent_code, our_code) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(StrategyError::MissingColumn, line, line))?;
+        let opponent = rules.opponent_move(opponent_code).ok_or_else(|| {
+            ParseError::at(StrategyError::UnknownOpponentMove, line, opponent_code)
+        })?;
+        let our = match decoding {
+            Decoding::Literal => rules.our_move(our_code),
+            Decoding::Strategic => {
+                RuleSet::outcome_code(our_code).map(|outcome| rules.response(opponent, outcome))
+            }
+        }
+        .ok_or_else(|| ParseError::at(StrategyError::UnknownResponse, line, our_code))?;
+        Ok(Game { our, opponent })
+    }
+
+    pub fn outcome(&self, rules: &RuleSet) -> Outcome {
+        rules.outcome(self.our, self.opponent)
+    }
+
+    pub fn score(&self, rules: &RuleSet) -> GameScore {
+        rules.score(self.our, self.opponent)
+    }
+}
+
+pub type GameScore = i32;
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn given() {
+        let input = "A Y\nB X\nC Z\n";
+
+        assert_eq!(part_1(input).unwrap(), 15);
+        assert_eq!(part_2(input).unwrap(), 12);
+    }
+
+    #[test]
+    fn unknown_response_is_located() {
+        let error = part_1("A Y\nB Q").unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(StrategyError::UnknownResponse, 2, 3, "Q")
+        );
+    }
+
+    #[test]
+    fn rock_beats_scissors_beats_paper_beats_rock() {
+        let rules = RuleSet::rock_paper_scissors();
+        let [rock, paper, scissors] = [0, 1, 2].map(Move);
+
+        assert_eq!(rules.outcome(rock, scissors), Outcome::Win);
+        assert_eq!(rules.outcome(scissors, paper), Outcome::Win);
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Loss);
+        assert_eq!(rules.outcome(paper, paper), Outcome::Draw);
+        assert_eq!(rules.score(scissors, rock), 3);
+    }
+
+    #[test]
+    fn every_move_beats_half_the_others() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+        let name = |m: Move| rules.name(m).to_string();
+
+        for our in rules.moves() {
+            let beaten: Vec<_> = rules
+                .moves()
+                .filter(|&their| rules.outcome(our, their) == Outcome::Win)
+                .map(name)
+                .collect();
+            assert_eq!(beaten.len(), 2, "{} beats {beaten:?}", name(our));
+        }
+        assert_eq!(
+            rules.outcome(Move(3), Move(1)),
+            Outcome::Win,
+            "lizard poisons Spock"
+        );
+        assert_eq!(
+            rules.outcome(Move(0), Move(3)),
+            Outcome::Win,
+            "rock crushes lizard"
+        );
+    }
+
+    #[test]
+    fn responses_get_the_outcome_asked_for() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+
+        for their in rules.moves() {
+            for outcome in Outcome::ALL {
+                assert_eq!(
+                    rules.outcome(rules.response(their, outcome), their),
+                    outcome
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn scores_are_configurable() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock()
+            .with_shape_scores(&[10, 20, 30, 40, 50])
+            .with_outcome_scores(-1, 0, 1);
+
+        assert_eq!(
+            score_guide("A V\nE Z\nC Y", &rules, Decoding::Literal).unwrap(),
+            10 + 50 + 40 + 1
+        );
+        assert_eq!(
+            score_guide("B Z", &rules, Decoding::Strategic).unwrap(),
+            30 + 1
+        );
+    }
+
+    #[test]
+    fn prod() {
+        let input = include_str!("../../inputs/day02/prod.txt");
+
+        assert_eq!(part_1(input).unwrap(), 11841);
+        assert_eq!(part_2(input).unwrap(), 13022);
+    }
+
+    #[test]
+    fn best_plan_wins_every_round() {
+        let rules = RuleSet::rock_paper_scissors();
+        let opponent = parse_opponent_moves("A Y\nB X\nC Z\n", &rules).unwrap();
+
+        let plan = best_plan(&rules, &opponent);
+
+        assert_eq!(
+            plan,
+            Plan {
+                score: 8 + 9 + 7,
+                responses: vec![Move(1), Move(2), Move(0)]
+            }
+        );
+    }
+
+    #[test]
+    fn best_plan_follows_the_scores() {
+        // Scissors is worth so much that losing with it beats winning.
+        let rules = RuleSet::rock_paper_scissors().with_shape_scores(&[1, 2, 100]);
+        let opponent = parse_opponent_moves("A\nC", &rules).unwrap();
+
+        let plan = best_plan(&rules, &opponent);
+
+        assert_eq!(plan.responses, vec![Move(2), Move(2)]);
+        assert_eq!(plan.score, 100 + 103);
+    }
+
+    #[test]
+    fn strategies_learn_the_opponent() {
+        let rules = RuleSet::rock_paper_scissors();
+        let opponent = parse_opponent_moves(&"A\nB\nC\n".repeat(10), &rules).unwrap();
+
+        let results = tournament(
+            &rules,
+            &opponent,
+            vec![
+                Box::new(Fixed(Move(0))),
+                Box::new(FrequencyCounter::default()),
+                Box::new(MarkovPredictor::default()),
+            ],
+        );
+
+        let names: Vec<_> = results.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["markov", "frequency", "always Rock"]);
+        // Markov only needs to see each move followed once.
+        let (_, markov) = results[0];
+        assert_eq!((markov.wins, markov.draws, markov.losses), (26, 4, 0));
+        assert_eq!(
+            results[2].1.to_string(),
+            "10W 10D 10L (33.3% won), score 120"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::GameScore;
+
+/// A move, numbered by its place in its rule set's cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Move(pub usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+/// An odd number of moves arranged in a cycle, where each move beats the
+/// half of the others that come just before it. With three moves this is
+/// rock-paper-scissors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleSet {
+    names: Vec<String>,
+    shape_scores: Vec<GameScore>,
+    loss: GameScore,
+    draw: GameScore,
+    win: GameScore,
+    opponent_codes: Vec<char>,
+    response_codes: Vec<char>,
+}
+
+impl RuleSet {
+    /// A game over `names`, in cycle order. Shapes score 1, 2, 3... in that
+    /// order and games score 0, 3 and 6 for a loss, draw and win. The
+    /// opponent's moves are coded `A`, `B`, `C`... and ours are coded with as
+    /// many letters as needed ending in `Z`.
+    ///
+    /// Panics unless there is an odd number of moves, or if there are more
+    /// than 26.
+    pub fn new(names: &[&str]) -> Self {
+        let n = names.len();
+        assert!(n % 2 == 1, "a cyclic game needs an odd number of moves");
+        assert!(n <= 26, "moves are coded by single letters");
+        RuleSet {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            shape_scores: (1..).take(n).collect(),
+            loss: 0,
+            draw: 3,
+            win: 6,
+            opponent_codes: ('A'..='Z').take(n).collect(),
+            response_codes: ('A'..='Z').skip(26 - n).collect(),
+        }
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        RuleSet::new(&["Rock", "Paper", "Scissors"])
+    }
+
+    /// Ordered so each move beats the two before it: Spock smashes rock,
+    /// paper disproves Spock, and so on around the cycle.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        RuleSet::new(&["Rock", "Spock", "Paper", "Lizard", "Scissors"])
+    }
+
+    /// Panics unless there is one score per move.
+    pub fn with_shape_scores(mut self, scores: &[GameScore]) -> Self {
+        assert_eq!(scores.len(), self.len(), "every move needs a score");
+        self.shape_scores = scores.to_vec();
+        self
+    }
+
+    pub fn with_outcome_scores(mut self, loss: GameScore, draw: GameScore, win: GameScore) -> Self {
+        self.loss = loss;
+        self.draw = draw;
+        self.win = win;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.len()).map(Move)
+    }
+
+    pub fn name(&self, m: Move) -> &str {
+        &self.names[m.0]
+    }
+
+    /// How `our` move fares against `their`s.
+    pub fn outcome(&self, our: Move, their: Move) -> Outcome {
+        let n = self.len();
+        match (our.0 + n - their.0) % n {
+            0 => Outcome::Draw,
+            ahead if ahead <= n / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    pub fn shape_score(&self, m: Move) -> GameScore {
+        self.shape_scores[m.0]
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> GameScore {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+
+    /// What playing `our` move against `their`s is worth to us.
+    pub fn score(&self, our: Move, their: Move) -> GameScore {
+        self.shape_score(our) + self.outcome_score(self.outcome(our, their))
+    }
+
+    /// A move that gets `outcome` against `their`s. When several would, this
+    /// is the neighbour of their move in the cycle.
+    pub fn response(&self, their: Move, outcome: Outcome) -> Move {
+        let n = self.len();
+        Move(match outcome {
+            Outcome::Loss => (their.0 + n - 1) % n,
+            Outcome::Draw => their.0,
+            Outcome::Win => (their.0 + 1) % n,
+        })
+    }
+
+    pub fn opponent_move(&self, code: &str) -> Option<Move> {
+        decode(&self.opponent_codes, code)
+    }
+
+    pub fn our_move(&self, code: &str) -> Option<Move> {
+        decode(&self.response_codes, code)
+    }
+
+    /// Strategy guides code outcomes as `X`, `Y` and `Z` whatever the game.
+    pub fn outcome_code(code: &str) -> Option<Outcome> {
+        decode(&['X', 'Y', 'Z'], code).map(|Move(index)| Outcome::ALL[index])
+    }
+}
+
+fn decode(codes: &[char], code: &str) -> Option<Move> {
+    let mut chars = code.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    codes.iter().position(|&known| known == c).map(Move)
+}
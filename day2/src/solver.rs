@@ -0,0 +1,55 @@
+use crate::{GameScore, Move, Outcome, RuleSet, StrategyError};
+use aoc_common::ParseError;
+
+/// The best responses to a known sequence of opponent moves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Plan {
+    pub score: GameScore,
+    pub responses: Vec<Move>,
+}
+
+/// Reads just the opponent's column of a strategy guide, so lines may have
+/// a response column or not.
+pub fn parse_opponent_moves(
+    input: &str,
+    rules: &RuleSet,
+) -> Result<Vec<Move>, ParseError<StrategyError>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            let code = line.split(' ').next().unwrap_or(line);
+            rules.opponent_move(code).ok_or_else(|| {
+                ParseError::at(StrategyError::UnknownOpponentMove, line, code).offset_lines(index)
+            })
+        })
+        .collect()
+}
+
+/// Rounds are scored independently, so the best plan takes the best-scoring
+/// response in every round. Ties go to the response that wins, then to the
+/// earliest move in the cycle.
+pub fn best_plan(rules: &RuleSet, opponent: &[Move]) -> Plan {
+    let responses: Vec<Move> = opponent
+        .iter()
+        .map(|&their| {
+            rules
+                .moves()
+                .max_by_key(|&our| {
+                    (
+                        rules.score(our, their),
+                        rules.outcome(our, their) == Outcome::Win,
+                        std::cmp::Reverse(our),
+                    )
+                })
+                .expect("a rule set has at least one move")
+        })
+        .collect();
+    let score = responses
+        .iter()
+        .zip(opponent)
+        .map(|(&our, &their)| rules.score(our, their))
+        .sum();
+    Plan { score, responses }
+}
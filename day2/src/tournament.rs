@@ -0,0 +1,183 @@
+use crate::{Game, GameScore, Move, Outcome, RuleSet};
+use std::fmt;
+
+/// A way of choosing moves that only learns the opponent's moves as they are
+/// played.
+pub trait Strategy {
+    fn name(&self, rules: &RuleSet) -> String;
+    fn choose(&mut self, rules: &RuleSet) -> Move;
+    /// Called with the opponent's move after every round.
+    fn observe(&mut self, their: Move);
+}
+
+/// Always plays the same move.
+pub struct Fixed(pub Move);
+
+impl Strategy for Fixed {
+    fn name(&self, rules: &RuleSet) -> String {
+        format!("always {}", rules.name(self.0))
+    }
+
+    fn choose(&mut self, _: &RuleSet) -> Move {
+        self.0
+    }
+
+    fn observe(&mut self, _: Move) {}
+}
+
+/// Beats whichever move the opponent has played most often so far.
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: Vec<usize>,
+}
+
+impl Strategy for FrequencyCounter {
+    fn name(&self, _: &RuleSet) -> String {
+        "frequency".to_string()
+    }
+
+    fn choose(&mut self, rules: &RuleSet) -> Move {
+        match most_common(&self.counts) {
+            Some(predicted) => rules.response(predicted, Outcome::Win),
+            None => Move(0),
+        }
+    }
+
+    fn observe(&mut self, their: Move) {
+        count(&mut self.counts, their);
+    }
+}
+
+/// Predicts the opponent's next move from what has followed their last move
+/// before, and beats that.
+#[derive(Default)]
+pub struct MarkovPredictor {
+    last: Option<Move>,
+    transitions: Vec<Vec<usize>>,
+}
+
+impl Strategy for MarkovPredictor {
+    fn name(&self, _: &RuleSet) -> String {
+        "markov".to_string()
+    }
+
+    fn choose(&mut self, rules: &RuleSet) -> Move {
+        let Some(last) = self.last else {
+            return Move(0);
+        };
+        // Until a move has been followed by anything, guess it repeats.
+        let predicted = self
+            .transitions
+            .get(last.0)
+            .and_then(|counts| most_common(counts))
+            .unwrap_or(last);
+        rules.response(predicted, Outcome::Win)
+    }
+
+    fn observe(&mut self, their: Move) {
+        if let Some(last) = self.last {
+            if self.transitions.len() <= last.0 {
+                self.transitions.resize_with(last.0 + 1, Vec::new);
+            }
+            count(&mut self.transitions[last.0], their);
+        }
+        self.last = Some(their);
+    }
+}
+
+fn count(counts: &mut Vec<usize>, m: Move) {
+    if counts.len() <= m.0 {
+        counts.resize(m.0 + 1, 0);
+    }
+    counts[m.0] += 1;
+}
+
+/// Ties go to the earliest move.
+fn most_common(counts: &[usize]) -> Option<Move> {
+    let (index, &count) = counts
+        .iter()
+        .enumerate()
+        .max_by_key(|&(index, &count)| (count, std::cmp::Reverse(index)))?;
+    (count > 0).then_some(Move(index))
+}
+
+/// How a strategy did over a run of games.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Record {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: GameScore,
+}
+
+impl Record {
+    pub fn games(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    /// The fraction of games won, from 0 to 1.
+    pub fn win_rate(&self) -> f64 {
+        if self.games() == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games() as f64
+        }
+    }
+
+    fn add(&mut self, game: Game, rules: &RuleSet) {
+        match game.outcome(rules) {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Loss => self.losses += 1,
+        }
+        self.score += game.score(rules);
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}W {}D {}L ({:.1}% won), score {}",
+            self.wins,
+            self.draws,
+            self.losses,
+            self.win_rate() * 100.0,
+            self.score
+        )
+    }
+}
+
+/// Plays `strategy` against the opponent's moves in order.
+pub fn simulate(rules: &RuleSet, opponent: &[Move], strategy: &mut dyn Strategy) -> Record {
+    let mut record = Record::default();
+    for &their in opponent {
+        let our = strategy.choose(rules);
+        record.add(
+            Game {
+                our,
+                opponent: their,
+            },
+            rules,
+        );
+        strategy.observe(their);
+    }
+    record
+}
+
+/// Plays each strategy against the same opponent, best score first.
+pub fn tournament(
+    rules: &RuleSet,
+    opponent: &[Move],
+    strategies: Vec<Box<dyn Strategy>>,
+) -> Vec<(String, Record)> {
+    let mut results: Vec<_> = strategies
+        .into_iter()
+        .map(|mut strategy| {
+            let record = simulate(rules, opponent, strategy.as_mut());
+            (strategy.name(rules), record)
+        })
+        .collect();
+    results.sort_by_key(|(_, record)| std::cmp::Reverse(record.score));
+    results
+}
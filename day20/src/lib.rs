@@ -1,27 +1,65 @@
-use std::{collections::VecDeque, str::FromStr};
+use std::{collections::VecDeque, fmt, str::FromStr};
 
-fn main() {
-    println!("Hello, world!");
-    let input = include_str!("../input.prod");
-    let output = part_2(input);
-    println!("Output is {output}");
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use tracing::trace;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
 }
 
-fn part_1(input: &str) -> i64 {
-    let mut circular_vec: CircularVec = input.parse().unwrap();
+pub fn part_1(input: &str) -> Result<i64, ParseError<FileError>> {
+    let mut circular_vec = parse_file(input)?;
     circular_vec.swap_all_items(1);
     //println!("{circular_vec:?}");
-    circular_vec.score()
+    Ok(circular_vec.score())
 }
 
-fn part_2(input: &str) -> i64 {
-    let mut circular_vec: CircularVec = input.parse().unwrap();
+pub fn part_2(input: &str) -> Result<i64, ParseError<FileError>> {
+    let mut circular_vec = parse_file(input)?;
     circular_vec
         .data
         .iter_mut()
-        .for_each(|elem| elem.value = elem.value * 811589153);
+        .for_each(|elem| elem.value *= 811589153);
     circular_vec.swap_all_items(10);
-    circular_vec.score()
+    Ok(circular_vec.score())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileError {
+    NotANumber,
+    TooFewNumbers,
+    MissingZero,
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileError::NotANumber => write!(f, "expected a number"),
+            FileError::TooFewNumbers => write!(f, "need at least two numbers to mix"),
+            FileError::MissingZero => write!(f, "coordinates are counted from 0, which is missing"),
+        }
+    }
+}
+
+/// Parses the encrypted file and checks it can be mixed and scored.
+fn parse_file(input: &str) -> Result<CircularVec, ParseError<FileError>> {
+    let circular_vec: CircularVec = input.parse()?;
+    let end = &input[input.len()..];
+    if circular_vec.data.len() < 2 {
+        return Err(ParseError::at(FileError::TooFewNumbers, input, end));
+    }
+    if !circular_vec.data.iter().any(|num| num.value == 0) {
+        return Err(ParseError::at(FileError::MissingZero, input, end));
+    }
+    Ok(circular_vec)
 }
 
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -30,15 +68,38 @@ struct UniqueNumber {
     starting_index: usize,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 struct CircularVec {
     data: VecDeque<UniqueNumber>,
 }
 
+/// Two circular lists are equal if one is a rotation of the other, since the
+/// puzzle only cares about the order of the numbers relative to each other.
+impl PartialEq for CircularVec {
+    fn eq(&self, other: &Self) -> bool {
+        if self.data.len() != other.data.len() {
+            return false;
+        }
+        if self.data.is_empty() {
+            return true;
+        }
+        (0..other.data.len()).any(|offset| {
+            self.data
+                .iter()
+                .zip(other.data.iter().cycle().skip(offset))
+                .all(|(left, right)| left == right)
+        })
+    }
+}
+
 impl CircularVec {
     fn score(&self) -> i64 {
-        let zero_index = self.data.iter().position(|&num| num.value == 0).unwrap();
-        let values = vec![
+        let zero_index = self
+            .data
+            .iter()
+            .position(|&num| num.value == 0)
+            .unwrap_or(0);
+        let values = [
             self.at(zero_index + 1000),
             self.at(zero_index + 2000),
             self.at(zero_index + 3000),
@@ -50,44 +111,35 @@ impl CircularVec {
     }
     fn swap_forward(&mut self, index: usize) -> usize {
         if index == self.data.len() - 1 {
-            let ahead_data = self.data[0];
-            self.data[0] = self.data[index];
-            self.data[index] = ahead_data;
+            self.data.swap(0, index);
             0
         } else {
-            let ahead_data = self.data[index + 1];
-            self.data[index + 1] = self.data[index];
-            self.data[index] = ahead_data;
+            self.data.swap(index + 1, index);
             index + 1
         }
     }
     fn swap_backward(&mut self, index: usize) -> usize {
         if index == 0 {
             let new_index = self.data.len() - 1;
-            let behind_data = self.data[new_index];
-            self.data[new_index] = self.data[0];
-            self.data[0] = behind_data;
+            self.data.swap(new_index, 0);
             new_index
         } else {
             let new_index = index - 1;
-            let behind_data = self.data[new_index];
-            self.data[new_index] = self.data[index];
-            self.data[index] = behind_data;
+            self.data.swap(new_index, index);
             new_index
         }
     }
     fn swap_item(&mut self, val: UniqueNumber) {
         let mut index = self.data.iter().position(|&item| item == val).unwrap();
-        let spaces_to_move = val.value % i64::try_from(self.data.len()).unwrap();
+        let _spaces_to_move = val.value % i64::try_from(self.data.len()).unwrap();
         let spaces_to_move = val.value % i64::try_from(self.data.len() - 1).unwrap();
         //let spaces_to_move = val.value;
+        trace!(value = val.value, spaces_to_move, "mixing a number");
         if spaces_to_move < 0 {
-            println!("Entered neg with {spaces_to_move} from {:?}", val.value);
             for _ in 0..-spaces_to_move {
                 index = self.swap_backward(index);
             }
         } else if spaces_to_move > 0 {
-            println!("Entered pos with {spaces_to_move} from {:?}", val.value);
             for _ in 0..spaces_to_move {
                 index = self.swap_forward(index);
             }
@@ -96,7 +148,7 @@ impl CircularVec {
     fn swap_all_items(&mut self, count: usize) {
         let data_clone = self.data.clone();
         //println!("Initial is {data_clone:#?}");
-        for i in 0..count {
+        for _i in 0..count {
             for item in data_clone.iter() {
                 self.swap_item(*item);
             }
@@ -106,16 +158,22 @@ impl CircularVec {
 }
 
 impl FromStr for CircularVec {
-    type Err = String;
+    type Err = ParseError<FileError>;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let data: VecDeque<UniqueNumber> = s
+        let data = s
             .lines()
             .enumerate()
-            .map(|(i, line)| UniqueNumber {
-                value: line.parse().unwrap(),
-                starting_index: i,
+            .map(|(i, line)| {
+                let value = line
+                    .trim()
+                    .parse()
+                    .map_err(|_| ParseError::at(FileError::NotANumber, s, line))?;
+                Ok(UniqueNumber {
+                    value,
+                    starting_index: i,
+                })
             })
-            .collect();
+            .collect::<Result<VecDeque<UniqueNumber>, _>>()?;
         Ok(CircularVec { data })
     }
 }
@@ -126,27 +184,27 @@ mod test {
 
     #[test]
     fn part_1_dev() {
-        let input = include_str!("../input.dev");
+        let input = include_str!("../../inputs/day20/dev.txt");
 
-        let output = part_1(input);
+        let output = part_1(input).unwrap();
 
         assert_eq!(output, 3);
     }
 
     #[test]
     fn part_1_prod() {
-        let input = include_str!("../input.prod");
+        let input = include_str!("../../inputs/day20/prod.txt");
 
-        let output = part_1(input);
+        let output = part_1(input).unwrap();
 
         assert_eq!(output, 5904);
     }
 
     #[test]
     fn part_2_dev() {
-        let input = include_str!("../input.dev");
+        let input = include_str!("../../inputs/day20/dev.txt");
 
-        let output = part_2(input);
+        let output = part_2(input).unwrap();
 
         assert_eq!(output, 1623178306);
     }
@@ -256,4 +314,11 @@ mod test {
 
         assert_eq!(one, 1);
     }
+
+    #[test]
+    fn bad_number_is_located() {
+        let error = part_1("1\n2\n-3x\n0").unwrap_err();
+
+        assert_eq!(error, ParseError::new(FileError::NotANumber, 3, 1, "-3x"));
+    }
 }
@@ -0,0 +1,657 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+use tracing::debug;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobError {
+    MissingColon,
+    NotANumber,
+    UnknownOperator,
+    MalformedJob,
+    UnknownMonkey,
+    MissingRoot,
+    RootIsNotAnEquation,
+    Cycle,
+    HumanOnBothSides,
+    DivisionByZero,
+}
+
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JobError::MissingColon => write!(f, "expected `name: job`"),
+            JobError::NotANumber => write!(f, "expected a number"),
+            JobError::UnknownOperator => write!(f, "expected one of `+`, `-`, `*` or `/`"),
+            JobError::MalformedJob => {
+                write!(f, "expected a number or `monkey operator monkey`")
+            }
+            JobError::UnknownMonkey => write!(f, "no monkey has this name"),
+            JobError::MissingRoot => write!(f, "no monkey is called `root`"),
+            JobError::RootIsNotAnEquation => write!(f, "`root` must compare two monkeys"),
+            JobError::Cycle => write!(f, "this monkey's job depends on its own number"),
+            JobError::HumanOnBothSides => {
+                write!(
+                    f,
+                    "both monkeys here depend on `humn`, so it cannot be solved for"
+                )
+            }
+            JobError::DivisionByZero => write!(f, "working out this job divides by zero"),
+        }
+    }
+}
+
+/// Why a job could not be worked out, and the monkey doing it.
+type JobFailure<'a> = (JobError, &'a str);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct MathEquation {
+    left: String,
+    right: String,
+    operator: Operator,
+}
+
+impl MathEquation {
+    fn new(left: String, right: String, operator: Operator) -> Self {
+        Self {
+            left,
+            right,
+            operator,
+        }
+    }
+    fn calculate_operator<'a>(
+        &self,
+        name: &'a str,
+        left_value: i64,
+        right_value: i64,
+    ) -> Result<i64, JobFailure<'a>> {
+        Ok(match self.operator {
+            Operator::Add => left_value + right_value,
+            Operator::Subtract => left_value - right_value,
+            Operator::Multiply => left_value * right_value,
+            Operator::Divide if right_value == 0 => return Err((JobError::DivisionByZero, name)),
+            Operator::Divide => left_value / right_value,
+        })
+    }
+    fn get_value<'a>(
+        &'a self,
+        name: &'a str,
+        all_monkeys: &'a HashMap<String, Monkey>,
+    ) -> Result<i64, JobFailure<'a>> {
+        let left_value = all_monkeys
+            .get(&self.left)
+            .unwrap()
+            .get_value(all_monkeys)?;
+        let right_value = all_monkeys
+            .get(&self.right)
+            .unwrap()
+            .get_value(all_monkeys)?;
+        self.calculate_operator(name, left_value, right_value)
+    }
+    fn get_optional_value<'a>(
+        &'a self,
+        name: &'a str,
+        all_monkeys: &'a HashMap<String, Monkey>,
+    ) -> Result<Option<i64>, JobFailure<'a>> {
+        let Some(left_value) = all_monkeys
+            .get(&self.left)
+            .unwrap()
+            .get_optional_value(all_monkeys)?
+        else {
+            return Ok(None);
+        };
+        let Some(right_value) = all_monkeys
+            .get(&self.right)
+            .unwrap()
+            .get_optional_value(all_monkeys)?
+        else {
+            return Ok(None);
+        };
+        self.calculate_operator(name, left_value, right_value)
+            .map(Some)
+    }
+    /// Fails at the first monkey found whose two monkeys both depend on
+    /// `humn`, or whose job cannot be undone without dividing by zero,
+    /// starting with `name`, the monkey doing this job.
+    fn find_value_to_make_equal<'a>(
+        &'a self,
+        name: &'a str,
+        target_value: i64,
+        all_monkeys: &'a HashMap<String, Monkey>,
+    ) -> Result<i64, JobFailure<'a>> {
+        let left_monkey_value = all_monkeys
+            .get(&self.left)
+            .unwrap()
+            .get_optional_value(all_monkeys)?;
+        let right_monkey_value = all_monkeys
+            .get(&self.right)
+            .unwrap()
+            .get_optional_value(all_monkeys)?;
+        let divide = |dividend: i64, divisor: i64| match divisor {
+            0 => Err((JobError::DivisionByZero, name)),
+            _ => Ok(dividend / divisor),
+        };
+
+        if let Some(left_concrete) = left_monkey_value {
+            let new_target = match self.operator {
+                Operator::Add => target_value - left_concrete,
+                Operator::Subtract => -(target_value - left_concrete),
+                Operator::Multiply => divide(target_value, left_concrete)?,
+                Operator::Divide => divide(left_concrete, target_value)?,
+            };
+            all_monkeys
+                .get(&self.right)
+                .unwrap()
+                .find_value_to_make_equal(new_target, all_monkeys)
+        } else if let Some(right_concrete) = right_monkey_value {
+            let new_target = match self.operator {
+                Operator::Add => target_value - right_concrete,
+                Operator::Subtract => target_value + right_concrete,
+                Operator::Multiply => divide(target_value, right_concrete)?,
+                Operator::Divide => target_value * right_concrete,
+            };
+            all_monkeys
+                .get(&self.left)
+                .unwrap()
+                .find_value_to_make_equal(new_target, all_monkeys)
+        } else {
+            Err((JobError::HumanOnBothSides, name))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Hash, Eq, Clone)]
+enum MonkeyAlgorithm {
+    Equation(MathEquation),
+    Constant(i64),
+}
+
+#[derive(Debug, PartialEq, Hash, Eq, Clone)]
+struct Monkey {
+    name: String,
+    algorithm: MonkeyAlgorithm,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError<JobError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, algorithm) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(JobError::MissingColon, s, s))?;
+        Ok(Monkey {
+            name: name.to_string(),
+            algorithm: algorithm
+                .parse()
+                .map_err(|error: ParseError<JobError>| error.within(s, algorithm))?,
+        })
+    }
+}
+
+impl Monkey {
+    fn get_value<'a>(
+        &'a self,
+        all_monkeys: &'a HashMap<String, Monkey>,
+    ) -> Result<i64, JobFailure<'a>> {
+        match &self.algorithm {
+            MonkeyAlgorithm::Constant(val) => Ok(*val),
+            MonkeyAlgorithm::Equation(math_equation) => {
+                math_equation.get_value(&self.name, all_monkeys)
+            }
+        }
+    }
+    fn get_optional_value<'a>(
+        &'a self,
+        all_monkeys: &'a HashMap<String, Monkey>,
+    ) -> Result<Option<i64>, JobFailure<'a>> {
+        if self.name == "humn" {
+            return Ok(None);
+        }
+        match &self.algorithm {
+            MonkeyAlgorithm::Constant(val) => Ok(Some(*val)),
+            MonkeyAlgorithm::Equation(math_equation) => {
+                math_equation.get_optional_value(&self.name, all_monkeys)
+            }
+        }
+    }
+    fn find_value_to_make_equal<'a>(
+        &'a self,
+        target_value: i64,
+        all_monkeys: &'a HashMap<String, Monkey>,
+    ) -> Result<i64, JobFailure<'a>> {
+        if self.name == "humn" {
+            return Ok(target_value);
+        }
+        match &self.algorithm {
+            MonkeyAlgorithm::Equation(math_equation) => {
+                math_equation.find_value_to_make_equal(&self.name, target_value, all_monkeys)
+            }
+            MonkeyAlgorithm::Constant(val) => Ok(*val),
+        }
+    }
+}
+
+impl FromStr for MonkeyAlgorithm {
+    type Err = ParseError<JobError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        if words.len() == 1 {
+            let constant = words[0]
+                .parse()
+                .map_err(|_| ParseError::at(JobError::NotANumber, s, words[0]))?;
+            Ok(MonkeyAlgorithm::Constant(constant))
+        } else if words.len() == 3 {
+            let left_monkey = words[0];
+            let operation = words[1];
+            let right_monkey = words[2];
+            let operator = match operation {
+                "+" => Operator::Add,
+                "-" => Operator::Subtract,
+                "/" => Operator::Divide,
+                "*" => Operator::Multiply,
+                _ => return Err(ParseError::at(JobError::UnknownOperator, s, operation)),
+            };
+            Ok(MonkeyAlgorithm::Equation(MathEquation::new(
+                left_monkey.to_string(),
+                right_monkey.to_string(),
+                operator,
+            )))
+        } else {
+            Err(ParseError::at(JobError::MalformedJob, s, s.trim()))
+        }
+    }
+}
+
+/// Parses every monkey by name, checking that each one they listen to exists
+/// and that there is a `root` to start from.
+fn parse_monkeys(input: &str) -> Result<HashMap<String, Monkey>, ParseError<JobError>> {
+    let mut jobs = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let monkey: Monkey = line
+            .parse()
+            .map_err(|error: ParseError<JobError>| error.offset_lines(index))?;
+        jobs.push((index, line, monkey));
+    }
+    let names: HashSet<&str> = jobs
+        .iter()
+        .map(|(_, _, monkey)| monkey.name.as_str())
+        .collect();
+    for (index, line, monkey) in &jobs {
+        let MonkeyAlgorithm::Equation(equation) = &monkey.algorithm else {
+            continue;
+        };
+        // The left monkey is named first after the colon and the right last.
+        let job = &line[monkey.name.len() + 1..];
+        let left = job.find(&equation.left).unwrap_or_default();
+        let right = job.rfind(&equation.right).unwrap_or_default();
+        for (name, at) in [(&equation.left, left), (&equation.right, right)] {
+            if !names.contains(name.as_str()) {
+                let span = &job[at..at + name.len()];
+                return Err(
+                    ParseError::at(JobError::UnknownMonkey, line, span).offset_lines(*index)
+                );
+            }
+        }
+    }
+    let monkeys: HashMap<String, Monkey> = jobs
+        .into_iter()
+        .map(|(_, _, monkey)| (monkey.name.clone(), monkey))
+        .collect();
+    if !monkeys.contains_key("root") {
+        return Err(ParseError::at(
+            JobError::MissingRoot,
+            input,
+            &input[input.len()..],
+        ));
+    }
+    // Walk in input order so the same cycle is always reported.
+    let mut finished = HashMap::new();
+    for line in input.lines() {
+        let name = line.split_once(':').map_or(line, |(name, _)| name);
+        if let Some(cycle) = find_cycle(name, &monkeys, &mut finished) {
+            let line = job_line(input, cycle);
+            return Err(ParseError::at(JobError::Cycle, input, line));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Walks the monkeys `name` listens to, depth first. `finished` maps each
+/// monkey visited to whether everything it listens to has been walked, so
+/// reaching one that has not means its job depends on itself. Returns the
+/// monkey whose job closes the cycle.
+fn find_cycle<'a>(
+    name: &'a str,
+    monkeys: &'a HashMap<String, Monkey>,
+    finished: &mut HashMap<&'a str, bool>,
+) -> Option<&'a str> {
+    match finished.get(name) {
+        Some(true) => return None,
+        Some(false) => return Some(name),
+        None => {}
+    }
+    finished.insert(name, false);
+    if let MonkeyAlgorithm::Equation(equation) = &monkeys[name].algorithm {
+        for next in [&equation.left, &equation.right] {
+            if let Some(cycle) = find_cycle(next, monkeys, finished) {
+                return Some(cycle);
+            }
+        }
+    }
+    finished.insert(name, true);
+    None
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError<JobError>> {
+    let monkeys = parse_monkeys(input)?;
+    monkeys["root"]
+        .get_value(&monkeys)
+        .map_err(|failure| located(input, failure))
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError<JobError>> {
+    let monkeys = parse_monkeys(input)?;
+    let root_monkey = &monkeys["root"];
+    if let MonkeyAlgorithm::Equation(math_equation) = &root_monkey.algorithm {
+        let left_monkey = monkeys.get(&math_equation.left).unwrap();
+        let right_monkey = monkeys.get(&math_equation.right).unwrap();
+        let located = |failure| located(input, failure);
+        let left_monkey_value = left_monkey.get_optional_value(&monkeys).map_err(located)?;
+        let right_monkey_value = right_monkey.get_optional_value(&monkeys).map_err(located)?;
+        if let Some(left_concrete) = left_monkey_value {
+            debug!(
+                value = left_concrete,
+                "the left side does not depend on humn"
+            );
+            right_monkey
+                .find_value_to_make_equal(left_concrete, &monkeys)
+                .map_err(located)
+        } else if let Some(right_concrete) = right_monkey_value {
+            debug!(
+                value = right_concrete,
+                "the right side does not depend on humn"
+            );
+            left_monkey
+                .find_value_to_make_equal(right_concrete, &monkeys)
+                .map_err(located)
+        } else {
+            Err(located((JobError::HumanOnBothSides, "root")))
+        }
+    } else {
+        let line = job_line(input, "root");
+        Err(ParseError::at(JobError::RootIsNotAnEquation, input, line))
+    }
+}
+
+/// The line giving `name`'s job.
+fn job_line<'a>(input: &'a str, name: &str) -> &'a str {
+    input
+        .lines()
+        .find(|line| line.split_once(':').is_some_and(|(job, _)| job == name))
+        .unwrap_or_default()
+}
+
+/// Points a failure at the line giving the job of the monkey it names.
+fn located(input: &str, (kind, name): JobFailure) -> ParseError<JobError> {
+    ParseError::at(kind, input, job_line(input, name))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day21/dev.txt");
+
+        let output = part_1(input).unwrap();
+
+        assert_eq!(output, 152);
+    }
+    #[test]
+    fn part_2_given() {
+        let input = include_str!("../../inputs/day21/dev.txt");
+
+        let output = part_2(input).unwrap();
+
+        assert_eq!(output, 301);
+    }
+
+    fn get_human() -> Monkey {
+        Monkey {
+            name: "humn".to_string(),
+            algorithm: MonkeyAlgorithm::Constant(0),
+        }
+    }
+
+    #[test]
+    fn add_equal() {
+        let left = get_human();
+        let right = Monkey {
+            name: "aaaa".to_string(),
+            algorithm: MonkeyAlgorithm::Constant(10),
+        };
+        let root = Monkey {
+            name: "root".to_string(),
+            algorithm: MonkeyAlgorithm::Equation(MathEquation::new(
+                "humn".to_string(),
+                "aaaa".to_string(),
+                Operator::Add,
+            )),
+        };
+        let all_monkeys: HashMap<String, Monkey> = vec![
+            (left.name.clone(), left),
+            (right.name.clone(), right),
+            (root.name.clone(), root.clone()),
+        ]
+        .into_iter()
+        .collect();
+
+        let equal_value = root.find_value_to_make_equal(100, &all_monkeys).unwrap();
+
+        assert_eq!(equal_value, 90);
+    }
+
+    #[test]
+    fn subtract() {
+        let left = get_human();
+        let right = Monkey {
+            name: "aaaa".to_string(),
+            algorithm: MonkeyAlgorithm::Constant(10),
+        };
+        let root = Monkey {
+            name: "root".to_string(),
+            algorithm: MonkeyAlgorithm::Equation(MathEquation::new(
+                "humn".to_string(),
+                "aaaa".to_string(),
+                Operator::Subtract,
+            )),
+        };
+        let all_monkeys: HashMap<String, Monkey> = vec![
+            (left.name.clone(), left),
+            (right.name.clone(), right),
+            (root.name.clone(), root.clone()),
+        ]
+        .into_iter()
+        .collect();
+
+        let equal_value = root.find_value_to_make_equal(100, &all_monkeys).unwrap();
+
+        assert_eq!(equal_value, 110);
+    }
+    #[test]
+    fn multiply_equal() {
+        let left = get_human();
+        let right = Monkey {
+            name: "aaaa".to_string(),
+            algorithm: MonkeyAlgorithm::Constant(10),
+        };
+        let root = Monkey {
+            name: "root".to_string(),
+            algorithm: MonkeyAlgorithm::Equation(MathEquation::new(
+                "humn".to_string(),
+                "aaaa".to_string(),
+                Operator::Multiply,
+            )),
+        };
+        let all_monkeys: HashMap<String, Monkey> = vec![
+            (left.name.clone(), left),
+            (right.name.clone(), right),
+            (root.name.clone(), root.clone()),
+        ]
+        .into_iter()
+        .collect();
+
+        let equal_value = root.find_value_to_make_equal(100, &all_monkeys).unwrap();
+
+        assert_eq!(equal_value, 10);
+    }
+    #[test]
+    fn divide_equal() {
+        let left = get_human();
+        let right = Monkey {
+            name: "aaaa".to_string(),
+            algorithm: MonkeyAlgorithm::Constant(10),
+        };
+        let root = Monkey {
+            name: "root".to_string(),
+            algorithm: MonkeyAlgorithm::Equation(MathEquation::new(
+                "humn".to_string(),
+                "aaaa".to_string(),
+                Operator::Divide,
+            )),
+        };
+        let all_monkeys: HashMap<String, Monkey> = vec![
+            (left.name.clone(), left),
+            (right.name.clone(), right),
+            (root.name.clone(), root.clone()),
+        ]
+        .into_iter()
+        .collect();
+
+        let equal_value = root.find_value_to_make_equal(100, &all_monkeys).unwrap();
+
+        assert_eq!(equal_value, 1000);
+    }
+
+    #[test]
+    fn monkey_constant_parse() {
+        let input = "abcd: 100";
+
+        let parsed_monkey: Monkey = input.parse().unwrap();
+
+        assert_eq!(
+            parsed_monkey,
+            Monkey {
+                name: "abcd".to_string(),
+                algorithm: MonkeyAlgorithm::Constant(100)
+            }
+        )
+    }
+
+    #[test]
+    fn unknown_operator_is_located() {
+        let error = part_1("root: abcd % efgh\nabcd: 1\nefgh: 2").unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(JobError::UnknownOperator, 1, 12, "%")
+        );
+    }
+
+    #[test]
+    fn unknown_monkey_is_located() {
+        let error = part_1("root: abcd + efgh\nabcd: 1").unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(JobError::UnknownMonkey, 1, 14, "efgh")
+        );
+    }
+
+    #[test]
+    fn unusual_unknown_names_are_located() {
+        assert_eq!(
+            part_1("root: a1 + bb\nbb: 1").unwrap_err(),
+            ParseError::new(JobError::UnknownMonkey, 1, 7, "a1")
+        );
+        assert_eq!(
+            part_1("root:aa + bb\naa: 1").unwrap_err(),
+            ParseError::new(JobError::UnknownMonkey, 1, 11, "bb")
+        );
+    }
+
+    #[test]
+    fn division_by_zero_is_located() {
+        let input =
+            "root: aaaa + bbbb\naaaa: cccc / dddd\nbbbb: humn * dddd\ncccc: 4\ndddd: 0\nhumn: 5\n";
+
+        assert_eq!(
+            part_1(input).unwrap_err(),
+            ParseError::new(JobError::DivisionByZero, 2, 1, "aaaa: cccc / dddd")
+        );
+        let input = input.replace("aaaa: cccc / dddd", "aaaa: cccc * dddd");
+        assert_eq!(
+            part_2(&input).unwrap_err(),
+            ParseError::new(JobError::DivisionByZero, 3, 1, "bbbb: humn * dddd")
+        );
+        let input = input.replace("bbbb: humn * dddd", "bbbb: cccc / humn");
+        assert_eq!(
+            part_2(&input).unwrap_err(),
+            ParseError::new(JobError::DivisionByZero, 3, 1, "bbbb: cccc / humn")
+        );
+    }
+
+    #[test]
+    fn human_on_both_sides_is_located() {
+        let input = "root: aaaa + bbbb\naaaa: humn * cccc\nbbbb: humn - cccc\ncccc: 3\nhumn: 5\n";
+        let nested =
+            "root: aaaa + dddd\naaaa: bbbb * humn\nbbbb: humn - cccc\ncccc: 3\ndddd: 4\nhumn: 5\n";
+
+        assert_eq!(
+            part_2(input).unwrap_err(),
+            ParseError::new(JobError::HumanOnBothSides, 1, 1, "root: aaaa + bbbb")
+        );
+        assert_eq!(
+            part_2(nested).unwrap_err(),
+            ParseError::new(JobError::HumanOnBothSides, 2, 1, "aaaa: bbbb * humn")
+        );
+    }
+
+    #[test]
+    fn cycles_are_located() {
+        let input = "root: aaaa + bbbb\naaaa: bbbb * cccc\nbbbb: 2\ncccc: aaaa - bbbb\nhumn: 5\n";
+
+        let error = part_1(input).unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(JobError::Cycle, 2, 1, "aaaa: bbbb * cccc")
+        );
+        assert!(part_2(input).is_err());
+    }
+}
@@ -0,0 +1,320 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{Diagnostic, Direction, Grid, ParseError, Point, Solution};
+use tracing::debug;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, _input: &str) -> Result<String> {
+        Err(anyhow!("day 22 part 2 has not been solved yet"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotesError {
+    MissingPath,
+    UnknownTile,
+    UnknownMove,
+    NotANumber,
+    NoOpenTile,
+}
+
+impl fmt::Display for NotesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotesError::MissingPath => {
+                write!(f, "expected a blank line between the map and the path")
+            }
+            NotesError::UnknownTile => write!(f, "expected `.`, `#` or a space"),
+            NotesError::UnknownMove => write!(f, "expected a number of tiles, `L` or `R`"),
+            NotesError::NotANumber => write!(f, "number of tiles is too large"),
+            NotesError::NoOpenTile => write!(f, "the top row has no open tile to start on"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Tile {
+    Wall,
+    Open,
+    Nothing,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Move {
+    Amount(usize),
+    Turn(Rotation),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Rotation {
+    Clockwise,
+    CounterClockwise,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Position {
+    point: Point,
+    orientation: Direction,
+}
+
+#[derive(PartialEq, Debug)]
+struct Game {
+    board: Grid<Tile>,
+    moves: Vec<Move>,
+    position: Position,
+}
+
+impl Game {
+    fn turn(&mut self, rotation: &Rotation) {
+        self.position.orientation = match rotation {
+            Rotation::Clockwise => self.position.orientation.turn_right(),
+            Rotation::CounterClockwise => self.position.orientation.turn_left(),
+        };
+    }
+    fn get_next_position(&self) -> Position {
+        Position {
+            point: self
+                .board
+                .wrap(self.position.point.step(self.position.orientation)),
+            orientation: self.position.orientation,
+        }
+    }
+    fn get_next_tile(&self) -> Tile {
+        let next_position = self.get_next_position();
+
+        self.board[next_position.point]
+    }
+    fn move_to_next_tile(&mut self) {
+        let next_position = self.get_next_position();
+        self.position = next_position;
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError<NotesError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (map, moves) = s
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at(NotesError::MissingPath, s, &s[s.len()..]))?;
+        let height = map.lines().count();
+        let width = map.lines().map(|line| line.len()).max().unwrap_or(0);
+        let mut raw_board = Grid::new(width, height, Tile::Nothing);
+        for (i, line) in map.lines().enumerate() {
+            for (j, (offset, tile)) in line.char_indices().enumerate() {
+                raw_board[Point::new(j as i64, i as i64)] = tile.try_into().map_err(|kind| {
+                    ParseError::at(kind, s, &line[offset..offset + tile.len_utf8()])
+                })?;
+            }
+        }
+        let x = if height == 0 {
+            0
+        } else {
+            raw_board
+                .row(0)
+                .iter()
+                .position(|tile| *tile == Tile::Open)
+                .ok_or_else(|| {
+                    ParseError::at(NotesError::NoOpenTile, s, map.lines().next().unwrap_or(map))
+                })?
+        };
+        Ok(Self {
+            board: raw_board,
+            moves: parse_moves(s, moves)?,
+            position: Position {
+                point: Point::new(x as i64, 0),
+                orientation: Direction::Right,
+            },
+        })
+    }
+}
+
+/// Splits the path into runs of tiles and turns. `moves` must be a slice of
+/// `source` so that errors point into the whole input.
+fn parse_moves(source: &str, moves: &str) -> Result<Vec<Move>, ParseError<NotesError>> {
+    let mut parsed = Vec::new();
+    let mut rest = moves.trim_end();
+    while let Some(c) = rest.chars().next() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let token_len = if digits > 0 {
+            let amount = &rest[..digits];
+            let amount = amount
+                .parse()
+                .map_err(|_| ParseError::at(NotesError::NotANumber, source, amount))?;
+            parsed.push(Move::Amount(amount));
+            digits
+        } else {
+            let turn = &rest[..c.len_utf8()];
+            let rotation = c
+                .try_into()
+                .map_err(|kind| ParseError::at(kind, source, turn))?;
+            parsed.push(Move::Turn(rotation));
+            turn.len()
+        };
+        rest = &rest[token_len..];
+    }
+    Ok(parsed)
+}
+
+impl TryFrom<char> for Rotation {
+    type Error = NotesError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'R' => Ok(Rotation::Clockwise),
+            'L' => Ok(Rotation::CounterClockwise),
+            _ => Err(NotesError::UnknownMove),
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = NotesError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Open),
+            ' ' => Ok(Tile::Nothing),
+            _ => Err(NotesError::UnknownTile),
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError<NotesError>> {
+    let mut game: Game = input.parse()?;
+    for game_move in game.moves.clone() {
+        match game_move {
+            Move::Turn(turn) => game.turn(&turn),
+            Move::Amount(amount) => {
+                for _ in 0..amount {
+                    let mut next_tile = game.get_next_tile();
+                    let pre_lookahead_position = game.position.clone();
+                    while next_tile == Tile::Nothing {
+                        game.move_to_next_tile();
+                        next_tile = game.get_next_tile();
+                    }
+                    if next_tile == Tile::Wall {
+                        game.position = pre_lookahead_position;
+                        break;
+                    }
+                    game.move_to_next_tile();
+                }
+            }
+        }
+    }
+    debug!(
+        row = game.position.point.y + 1,
+        column = game.position.point.x + 1,
+        facing = ?game.position.orientation,
+        "finished the path"
+    );
+    let row = game.position.point.y + 1;
+    let col = game.position.point.x + 1;
+    let orientation_score = match game.position.orientation {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    };
+    Ok((row * 1000 + 4 * col + orientation_score) as usize)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day22/dev.txt");
+
+        let output = part_1(input).unwrap();
+
+        assert_eq!(output, 6032);
+    }
+    #[test]
+    fn parse_basic_board() {
+        let input = " .#\n.#.\n\n";
+        let expected_board = Grid::from_rows(vec![
+            vec![Tile::Nothing, Tile::Open, Tile::Wall],
+            vec![Tile::Open, Tile::Wall, Tile::Open],
+        ])
+        .unwrap();
+
+        let actual_board = input.parse::<Game>().unwrap().board;
+
+        assert_eq!(expected_board, actual_board);
+    }
+
+    #[test]
+    fn parse_complex_board() {
+        let input = " .\n.#.\n\n";
+        let expected_board = Grid::from_rows(vec![
+            vec![Tile::Nothing, Tile::Open, Tile::Nothing],
+            vec![Tile::Open, Tile::Wall, Tile::Open],
+        ])
+        .unwrap();
+
+        let actual_board = input.parse::<Game>().unwrap().board;
+
+        assert_eq!(expected_board, actual_board);
+    }
+
+    #[test]
+    fn basic_moves() {
+        let input = "\n\n10L100R";
+        let expected_moves = vec![
+            Move::Amount(10),
+            Move::Turn(Rotation::CounterClockwise),
+            Move::Amount(100),
+            Move::Turn(Rotation::Clockwise),
+        ];
+
+        let actual_moves = input.parse::<Game>().unwrap().moves;
+
+        assert_eq!(expected_moves, actual_moves);
+    }
+
+    #[test]
+    fn real_moves() {
+        let input = "\n\n10L100R20";
+        let expected_moves = vec![
+            Move::Amount(10),
+            Move::Turn(Rotation::CounterClockwise),
+            Move::Amount(100),
+            Move::Turn(Rotation::Clockwise),
+            Move::Amount(20),
+        ];
+
+        let actual_moves = input.parse::<Game>().unwrap().moves;
+
+        assert_eq!(expected_moves, actual_moves);
+    }
+
+    #[test]
+    fn starting_position() {
+        let input = " .#\n\n";
+        let expected_position = Position {
+            point: Point::new(1, 0),
+            orientation: Direction::Right,
+        };
+
+        let actual_position = input.parse::<Game>().unwrap().position;
+
+        assert_eq!(expected_position, actual_position);
+    }
+
+    #[test]
+    fn unknown_move_is_located() {
+        let error = " .#\n\n2L3X".parse::<Game>().unwrap_err();
+
+        assert_eq!(error, ParseError::new(NotesError::UnknownMove, 3, 4, "X"));
+    }
+}
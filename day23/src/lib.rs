@@ -0,0 +1,434 @@
+use std::{collections::VecDeque, fmt, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{Diagnostic, Direction, Grid, ParseError, Point, RaggedGridError, Solution};
+use tracing::{debug, trace};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError<ScanError>> {
+    let mut grove: Grove = input.parse()?;
+    for _i in 0..10 {
+        let desired_moves = grove.get_all_requests();
+        grove.process_all_requests(desired_moves);
+        grove.cycle_direction_priorities();
+        //        println!("Grove {i}: {:#?}", grove);
+    }
+    Ok(grove.count_ground_squares())
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError<ScanError>> {
+    let mut grove: Grove = input.parse()?;
+    let mut i = 0;
+    loop {
+        i += 1;
+        trace!(round = i, "moving elves");
+        let desired_moves = grove.get_all_requests();
+        let moved_anything = grove.process_all_requests(desired_moves);
+        if !moved_anything {
+            break;
+        }
+        grove.cycle_direction_priorities();
+        //        println!("Grove {i}: {:#?}", grove);
+    }
+    Ok(i)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanError {
+    NotAPosition,
+    RaggedRows,
+    NoElves,
+}
+
+impl From<RaggedGridError> for ScanError {
+    fn from(_: RaggedGridError) -> Self {
+        ScanError::RaggedRows
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScanError::NotAPosition => write!(f, "expected `#` or `.`"),
+            ScanError::RaggedRows => write!(f, "every row of the scan must be the same width"),
+            ScanError::NoElves => write!(f, "the scan has no elves in it"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Position {
+    Empty,
+    Elf,
+}
+
+impl TryFrom<char> for Position {
+    type Error = ScanError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Position::Elf),
+            '.' => Ok(Position::Empty),
+            _ => Err(ScanError::NotAPosition),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Grove {
+    squares: Grid<Position>,
+    direction_priorities: VecDeque<Direction>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Move {
+    from: Point,
+    to: Point,
+}
+
+#[derive(Debug, PartialEq)]
+struct Edges {
+    max_x: usize,
+    min_x: usize,
+    max_y: usize,
+    min_y: usize,
+}
+
+impl Grove {
+    fn new(size: usize) -> Self {
+        Self {
+            squares: Grid::new(size, size, Position::Empty),
+            direction_priorities: VecDeque::from(vec![
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ]),
+        }
+    }
+    fn get_all_elves(&self) -> Vec<Point> {
+        self.squares
+            .iter()
+            .filter(|(_, position)| matches!(position, Position::Elf))
+            .map(|(point, _)| point)
+            .collect()
+    }
+    fn count_ground_squares(&self) -> usize {
+        let edges = self.find_edges();
+        debug!(?edges, "found the bounds of the elves");
+        let ground_squares = self
+            .squares
+            .rows()
+            .skip(edges.min_y)
+            .take(edges.max_y - edges.min_y + 1)
+            .map(|row| {
+                row[edges.min_x..=edges.max_x]
+                    .iter()
+                    .filter(|a| matches!(a, Position::Empty))
+                    .count()
+            })
+            .sum();
+
+        ground_squares
+    }
+    fn find_edges(&self) -> Edges {
+        let min_x = self
+            .squares
+            .rows()
+            .flat_map(|row| {
+                row.iter()
+                    .position(|position| matches!(position, Position::Elf))
+            })
+            .min()
+            .unwrap();
+        let width = self.squares.width();
+        let max_x = width
+            - 1
+            - self
+                .squares
+                .rows()
+                .flat_map(|row| {
+                    row.iter()
+                        .rev()
+                        .position(|position| matches!(position, Position::Elf))
+                })
+                .min()
+                .unwrap();
+        let min_y = self
+            .squares
+            .rows()
+            .position(|row| row.iter().any(|position| matches!(position, Position::Elf)))
+            .unwrap();
+        let height = self.squares.height();
+        let max_y = height
+            - 1
+            - self
+                .squares
+                .rows()
+                .rev()
+                .position(|row| row.iter().any(|position| matches!(position, Position::Elf)))
+                .unwrap();
+
+        Edges {
+            max_x,
+            min_x,
+            max_y,
+            min_y,
+        }
+    }
+    fn process_all_requests(&mut self, moves: Vec<Move>) -> bool {
+        let mut moved_anything = false;
+        for desired_move in moves.iter().filter(|m| m.to != m.from) {
+            if !moves
+                .iter()
+                .any(|other_move| (other_move.to == desired_move.to) && other_move != desired_move)
+            {
+                moved_anything = true;
+                self.squares[desired_move.from] = Position::Empty;
+                self.squares[desired_move.to] = Position::Elf;
+            }
+        }
+        moved_anything
+    }
+    fn get_all_requests(&self) -> Vec<Move> {
+        let elves = self.get_all_elves();
+        let requests: Vec<Move> = elves
+            .into_iter()
+            .map(|elf| {
+                let mut mut_move = Move { from: elf, to: elf };
+
+                if self.elf_has_empty_surroundings(&elf) {
+                    return mut_move;
+                }
+
+                for direction in self.direction_priorities.iter() {
+                    let optional_move = self.elf_consider_direction(&elf, direction);
+                    if let Some(concrete_move) = optional_move {
+                        mut_move = Move {
+                            from: elf,
+                            to: concrete_move,
+                        };
+                        break;
+                    }
+                }
+
+                mut_move
+            })
+            .collect();
+
+        requests
+    }
+
+    fn cycle_direction_priorities(&mut self) {
+        let front = self.direction_priorities.pop_front().unwrap();
+        self.direction_priorities.push_back(front);
+    }
+
+    fn elf_has_empty_surroundings(&self, elf_position: &Point) -> bool {
+        elf_position
+            .neighbours8()
+            .iter()
+            .all(|&neighbour| match self.squares[neighbour] {
+                Position::Elf => false,
+                Position::Empty => true,
+            })
+    }
+    fn elf_consider_direction(&self, elf_position: &Point, direction: &Direction) -> Option<Point> {
+        let destination = elf_position.step(*direction);
+        let desired_positions = [
+            destination.step(direction.turn_left()),
+            destination,
+            destination.step(direction.turn_right()),
+        ];
+        if desired_positions
+            .iter()
+            .any(|&position| match self.squares[position] {
+                Position::Empty => false,
+                Position::Elf => true,
+            })
+        {
+            None
+        } else {
+            Some(destination)
+        }
+    }
+}
+
+impl FromStr for Grove {
+    type Err = ParseError<ScanError>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut grove = Grove::new(0);
+        let unpadded_positions = Grid::try_parse(s, Position::try_from)?;
+        if unpadded_positions
+            .position(|position| matches!(position, Position::Elf))
+            .is_none()
+        {
+            return Err(ParseError::at(ScanError::NoElves, s, &s[s.len()..]));
+        }
+
+        let width = unpadded_positions.width();
+        let height = unpadded_positions.height();
+        let padding = 150;
+        let mut padded_grove =
+            Grid::new(width + 2 * padding, height + 2 * padding, Position::Empty);
+        let offset = Point::new(padding as i64, padding as i64);
+        for (point, position) in unpadded_positions.iter() {
+            padded_grove[point + offset] = position.clone();
+        }
+
+        grove.squares = padded_grove;
+
+        Ok(grove)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day23/dev.txt");
+
+        let output = part_1(input).unwrap();
+
+        assert_eq!(output, 110);
+    }
+
+    #[test]
+    fn consider_north() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(2, 2)] = Position::Elf;
+        let expected_coordinate = Point::new(2, 1);
+
+        let desired_cordinate = grove
+            .elf_consider_direction(&Point::new(2, 2), &Direction::Up)
+            .unwrap();
+
+        assert_eq!(desired_cordinate, expected_coordinate);
+    }
+
+    #[test]
+    fn consider_north_blocked() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(2, 2)] = Position::Elf;
+        grove.squares[Point::new(2, 1)] = Position::Elf;
+
+        let desired_coordinate = grove.elf_consider_direction(&Point::new(2, 2), &Direction::Up);
+
+        assert_eq!(desired_coordinate, None);
+    }
+
+    #[test]
+    fn consider_north_east_blocked() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(2, 2)] = Position::Elf;
+        grove.squares[Point::new(3, 1)] = Position::Elf;
+
+        let desired_coordinate = grove.elf_consider_direction(&Point::new(2, 2), &Direction::Up);
+
+        assert_eq!(desired_coordinate, None);
+    }
+
+    #[test]
+    fn consider_east() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(2, 2)] = Position::Elf;
+        let expected_coordinate = Point::new(3, 2);
+
+        let desired_cordinate = grove
+            .elf_consider_direction(&Point::new(2, 2), &Direction::Right)
+            .unwrap();
+
+        assert_eq!(desired_cordinate, expected_coordinate);
+    }
+
+    #[test]
+    fn consider_east_blocked() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(2, 2)] = Position::Elf;
+        grove.squares[Point::new(3, 1)] = Position::Elf;
+
+        let desired_coordinate = grove.elf_consider_direction(&Point::new(2, 2), &Direction::Right);
+
+        assert_eq!(desired_coordinate, None);
+    }
+
+    #[test]
+    fn get_all_elves() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(2, 2)] = Position::Elf;
+        grove.squares[Point::new(2, 1)] = Position::Elf;
+        let expected_elves = vec![Point::new(2, 1), Point::new(2, 2)];
+
+        let elves = grove.get_all_elves();
+
+        assert_eq!(expected_elves, elves);
+    }
+
+    #[test]
+    fn basic_empty_surroundings() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(2, 2)] = Position::Elf;
+
+        let surroundings_empty = grove.elf_has_empty_surroundings(&Point::new(2, 2));
+
+        assert!(surroundings_empty);
+    }
+
+    #[test]
+    fn basic_not_empty_surroundings() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(2, 2)] = Position::Elf;
+        grove.squares[Point::new(2, 1)] = Position::Elf;
+
+        let surroundings_empty = grove.elf_has_empty_surroundings(&Point::new(2, 2));
+
+        assert!(!surroundings_empty);
+    }
+
+    #[test]
+    fn find_edges() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(3, 2)] = Position::Elf;
+        grove.squares[Point::new(2, 1)] = Position::Elf;
+        let expected_edges = Edges {
+            min_x: 2,
+            max_x: 3,
+            min_y: 1,
+            max_y: 2,
+        };
+
+        let edges = grove.find_edges();
+
+        assert_eq!(edges, expected_edges);
+    }
+
+    #[test]
+    fn count_ground() {
+        let mut grove = Grove::new(5);
+        grove.squares[Point::new(4, 3)] = Position::Elf;
+        grove.squares[Point::new(2, 1)] = Position::Elf;
+
+        let ground_squares = grove.count_ground_squares();
+
+        assert_eq!(ground_squares, 7);
+    }
+
+    #[test]
+    fn bad_position_is_located() {
+        let error = "..#\n.x.".parse::<Grove>().unwrap_err();
+
+        assert_eq!(error, ParseError::new(ScanError::NotAPosition, 2, 2, "x"));
+    }
+}
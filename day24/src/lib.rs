@@ -1,15 +1,24 @@
 use std::cmp::Ordering;
 use std::collections::HashSet;
-use std::time::Instant;
-use std::{collections::BinaryHeap, str::FromStr};
+use std::{collections::BinaryHeap, fmt, str::FromStr};
 
-fn main() {
-    println!("Hello, world!");
-    let start = Instant::now();
-    let test_input = include_str!("../input.prod");
-    let result = solve(test_input, Version::Part2);
+use anyhow::Result;
+use aoc_common::{Diagnostic, Direction, Grid, ParseError, Point, RaggedGridError, Solution};
+use tracing::debug;
 
-    println!("Result is {result}, in {:?}", start.elapsed());
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(solve(input, Version::Part1)
+            .map_err(Diagnostic::from)?
+            .to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(solve(input, Version::Part2)
+            .map_err(Diagnostic::from)?
+            .to_string())
+    }
 }
 
 enum Version {
@@ -17,13 +26,13 @@ enum Version {
     Part2,
 }
 
-fn solve(input: &str, version: Version) -> usize {
-    let board: Board = input.parse().unwrap();
+fn solve(input: &str, version: Version) -> Result<usize, ParseError<ValleyError>> {
+    let board: Board = input.parse()?;
     let mut future_game_states = BinaryHeap::new();
     let start_move = GameState {
         part_of_game: PartOfGame::HeadingToEnd,
         move_number: 0,
-        position: Coordinate { x: 1, y: 0 },
+        position: Point::new(1, 0),
     };
     let mut considered_states: HashSet<GameState> = HashSet::new();
     future_game_states.push(Priority(start_move.score(&board), start_move));
@@ -31,7 +40,7 @@ fn solve(input: &str, version: Version) -> usize {
     while let Some(Priority(_, state)) = future_game_states.pop() {
         if state.is_complete(&board, &version) {
             if state.move_number < fewest_moves {
-                println!("Found a shorter finishing move of {:?}", state.move_number);
+                debug!(moves = state.move_number, "found a shorter route");
                 fewest_moves = state.move_number;
             }
             continue;
@@ -45,16 +54,17 @@ fn solve(input: &str, version: Version) -> usize {
         let valid_moves = state.next_moves(&board, &version);
 
         for valid_move in valid_moves.into_iter() {
-            if considered_states.get(&valid_move) == None {
+            if !considered_states.contains(&valid_move) {
                 considered_states.insert(valid_move.clone());
                 future_game_states.push(Priority(valid_move.score(&board), valid_move));
             }
         }
     }
-    fewest_moves
+    Ok(fewest_moves)
 }
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[allow(clippy::enum_variant_names)]
 enum PartOfGame {
     HeadingToEnd,
     HeadingBackToStart,
@@ -65,7 +75,7 @@ enum PartOfGame {
 pub struct GameState {
     part_of_game: PartOfGame,
     move_number: usize,
-    position: Coordinate,
+    position: Point,
 }
 
 impl GameState {
@@ -73,7 +83,7 @@ impl GameState {
         (match self.part_of_game {
             PartOfGame::HeadingToEnd => self.position.x + self.position.y,
             PartOfGame::HeadingBackToStart => {
-                100_000 + board.width - self.position.x + board.height - self.position.y
+                100_000 + board.width() - self.position.x + board.height() - self.position.y
             }
             PartOfGame::HeadingBackToEnd => 100_000_000 + self.position.x + self.position.y,
         }) as usize
@@ -109,27 +119,27 @@ impl GameState {
             GameState {
                 part_of_game,
                 move_number: next_move_number,
-                position: self.position.clone(),
+                position: self.position,
             },
             GameState {
                 part_of_game,
                 move_number: next_move_number,
-                position: self.position.left(1),
+                position: self.position.step(Direction::Left),
             },
             GameState {
                 part_of_game,
                 move_number: next_move_number,
-                position: self.position.up(1),
+                position: self.position.step(Direction::Up),
             },
             GameState {
                 part_of_game,
                 move_number: next_move_number,
-                position: self.position.right(1),
+                position: self.position.step(Direction::Right),
             },
             GameState {
                 part_of_game,
                 move_number: next_move_number,
-                position: self.position.down(1),
+                position: self.position.step(Direction::Down),
             },
         ];
         let valid_moves: Vec<GameState> = new_states
@@ -144,15 +154,6 @@ impl GameState {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Hash)]
-pub enum Direction {
-    Up,
-    Left,
-    Down,
-    Right,
-    Unknown,
-}
-
 #[derive(Clone, Debug, PartialEq, Hash)]
 pub enum Space {
     Empty,
@@ -160,103 +161,102 @@ pub enum Space {
     End,
     Start,
     Blizzard(Direction),
+    /// At least one blizzard, without tracking which way it is heading.
+    AnyBlizzard,
 }
 
-impl From<char> for Space {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Space {
+    type Error = ValleyError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '#' => Self::Wall,
-            '.' => Self::Empty,
-            '>' => Self::Blizzard(Direction::Right),
-            '^' => Self::Blizzard(Direction::Up),
-            'v' => Self::Blizzard(Direction::Down),
-            '<' => Self::Blizzard(Direction::Left),
-            _ => unreachable!(),
+            '#' => Ok(Self::Wall),
+            '.' => Ok(Self::Empty),
+            '>' => Ok(Self::Blizzard(Direction::Right)),
+            '^' => Ok(Self::Blizzard(Direction::Up)),
+            'v' => Ok(Self::Blizzard(Direction::Down)),
+            '<' => Ok(Self::Blizzard(Direction::Left)),
+            _ => Err(ValleyError::NotASpace),
         }
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub struct Coordinate {
-    x: i32,
-    y: i32,
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValleyError {
+    NotASpace,
+    RaggedRows,
+    TooSmall,
 }
 
-impl Coordinate {
-    fn up(&self, spaces: usize) -> Self {
-        Coordinate {
-            x: self.x,
-            y: self.y - (spaces as i32),
-        }
-    }
-    fn down(&self, spaces: usize) -> Self {
-        Coordinate {
-            x: self.x,
-            y: self.y + (spaces as i32),
-        }
-    }
-    fn left(&self, spaces: usize) -> Self {
-        Coordinate {
-            x: self.x - (spaces as i32),
-            y: self.y,
-        }
+impl From<RaggedGridError> for ValleyError {
+    fn from(_: RaggedGridError) -> Self {
+        ValleyError::RaggedRows
     }
-    fn right(&self, spaces: usize) -> Self {
-        Coordinate {
-            x: self.x + (spaces as i32),
-            y: self.y,
+}
+
+impl fmt::Display for ValleyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValleyError::NotASpace => write!(f, "expected `#`, `.` or a blizzard"),
+            ValleyError::RaggedRows => write!(f, "every row of the valley must be the same width"),
+            ValleyError::TooSmall => write!(f, "the valley needs walls around at least one space"),
         }
     }
-    fn new(x: i32, y: i32) -> Self {
-        Coordinate { x, y }
-    }
 }
 
 #[derive(PartialEq, Debug)]
 pub struct Board {
-    board: Vec<Vec<Space>>,
-    width: i32,
-    height: i32,
+    board: Grid<Space>,
 }
 
 impl Board {
-    fn distance_to_end(&self, coordinate: &Coordinate) -> usize {
-        ((self.width - coordinate.x) + (self.height - coordinate.y)) as usize
+    fn width(&self) -> i64 {
+        self.board.width() as i64
     }
-    fn at_initial_position(&self, coordinate: &Coordinate) -> Space {
-        let x = coordinate.x.rem_euclid(self.width);
-        let y = coordinate.y.rem_euclid(self.height);
-        self.board[y as usize][x as usize].clone()
+    fn height(&self) -> i64 {
+        self.board.height() as i64
     }
-    fn at_initial_position_blizzard(&self, coordinate: &Coordinate) -> Space {
-        let x = (coordinate.x - 1).rem_euclid(self.width - 2) + 1;
-        let y = (coordinate.y - 1).rem_euclid(self.height - 2) + 1;
-        self.board[y as usize][x as usize].clone()
+    fn distance_to_end(&self, coordinate: &Point) -> usize {
+        ((self.width() - coordinate.x) + (self.height() - coordinate.y)) as usize
     }
-    pub fn at(&self, coordinate: &Coordinate, move_number: usize) -> Space {
-        if !(coordinate.x.rem_euclid(self.width) == 0
-            || coordinate.x.rem_euclid(self.width) == self.width - 1
-            || coordinate.y.rem_euclid(self.height) == 0
-            || coordinate.y.rem_euclid(self.height) == self.height - 1)
+    fn at_initial_position(&self, coordinate: &Point) -> Space {
+        self.board[self.board.wrap(*coordinate)].clone()
+    }
+    fn at_initial_position_blizzard(&self, coordinate: &Point) -> Space {
+        let x = (coordinate.x - 1).rem_euclid(self.width() - 2) + 1;
+        let y = (coordinate.y - 1).rem_euclid(self.height() - 2) + 1;
+        self.board[Point::new(x, y)].clone()
+    }
+    pub fn at(&self, coordinate: &Point, move_number: usize) -> Space {
+        let wrapped = self.board.wrap(*coordinate);
+        if !(wrapped.x == 0
+            || wrapped.x == self.width() - 1
+            || wrapped.y == 0
+            || wrapped.y == self.height() - 1)
         {
-            let up_space = self.at_initial_position_blizzard(&coordinate.up(move_number));
+            let move_number = move_number as i64;
+            let up_space =
+                self.at_initial_position_blizzard(&coordinate.step_by(Direction::Up, move_number));
             if Space::Blizzard(Direction::Down) == up_space {
-                return Space::Blizzard(Direction::Unknown);
+                return Space::AnyBlizzard;
             }
-            let down_space = self.at_initial_position_blizzard(&coordinate.down(move_number));
+            let down_space = self
+                .at_initial_position_blizzard(&coordinate.step_by(Direction::Down, move_number));
             if Space::Blizzard(Direction::Up) == down_space {
-                return Space::Blizzard(Direction::Unknown);
+                return Space::AnyBlizzard;
             }
-            let left_space = self.at_initial_position_blizzard(&coordinate.left(move_number));
+            let left_space = self
+                .at_initial_position_blizzard(&coordinate.step_by(Direction::Left, move_number));
             if Space::Blizzard(Direction::Right) == left_space {
-                return Space::Blizzard(Direction::Unknown);
+                return Space::AnyBlizzard;
             }
-            let right_space = self.at_initial_position_blizzard(&coordinate.right(move_number));
+            let right_space = self
+                .at_initial_position_blizzard(&coordinate.step_by(Direction::Right, move_number));
             if Space::Blizzard(Direction::Left) == right_space {
-                return Space::Blizzard(Direction::Unknown);
+                return Space::AnyBlizzard;
             }
         }
-        let initial_space = self.at_initial_position(&coordinate);
+        let initial_space = self.at_initial_position(coordinate);
         match initial_space {
             Space::Blizzard(_) => Space::Empty,
             other => other,
@@ -265,21 +265,17 @@ impl Board {
 }
 
 impl FromStr for Board {
-    type Err = anyhow::Error;
+    type Err = ParseError<ValleyError>;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut board: Vec<Vec<Space>> = s
-            .lines()
-            .map(|line| line.trim().chars().map(|c| c.into()).collect())
-            .collect();
-        let width: i32 = board[0].len().try_into().unwrap();
-        let height: i32 = board.len().try_into().unwrap();
-        board[0][1] = Space::Start;
-        board[(height - 1) as usize][(width - 2) as usize] = Space::End;
-        Ok(Self {
-            width,
-            height,
-            board,
-        })
+        let mut board = Grid::try_parse(s, Space::try_from)?;
+        let width = board.width() as i64;
+        let height = board.height() as i64;
+        if width < 3 || height < 3 {
+            return Err(ParseError::at(ValleyError::TooSmall, s, &s[s.len()..]));
+        }
+        board[Point::new(1, 0)] = Space::Start;
+        board[Point::new(width - 2, height - 1)] = Space::End;
+        Ok(Self { board })
     }
 }
 
@@ -288,18 +284,18 @@ mod test {
     use crate::*;
     #[test]
     fn part_1_given() {
-        let input = include_str!("../input.dev");
+        let input = include_str!("../../inputs/day24/dev.txt");
 
-        let result = solve(input, Version::Part1);
+        let result = solve(input, Version::Part1).unwrap();
 
         assert_eq!(result, 18);
     }
 
     #[test]
     fn part_2_given() {
-        let input = include_str!("../input.dev");
+        let input = include_str!("../../inputs/day24/dev.txt");
 
-        let result = solve(input, Version::Part2);
+        let result = solve(input, Version::Part2).unwrap();
 
         assert_eq!(result, 54);
     }
@@ -308,7 +304,7 @@ mod test {
     fn parse_small_board() {
         let small_board = "#.##\n#<>#\n#^v#\n#..#\n##.#";
         let expected_board = Board {
-            board: vec![
+            board: Grid::from_rows(vec![
                 vec![Space::Wall, Space::Start, Space::Wall, Space::Wall],
                 vec![
                     Space::Wall,
@@ -324,9 +320,8 @@ mod test {
                 ],
                 vec![Space::Wall, Space::Empty, Space::Empty, Space::Wall],
                 vec![Space::Wall, Space::Wall, Space::End, Space::Wall],
-            ],
-            width: 4,
-            height: 5,
+            ])
+            .unwrap(),
         };
 
         let board: Board = small_board.parse().unwrap();
@@ -339,7 +334,7 @@ mod test {
         let small_board = "#.##\n#<>#\n#^v#\n#..#\n##.#";
         let board: Board = small_board.parse().unwrap();
 
-        let wall_left = board.at(&Coordinate::new(0, 1), 0);
+        let wall_left = board.at(&Point::new(0, 1), 0);
 
         assert_eq!(wall_left, Space::Wall);
     }
@@ -349,7 +344,7 @@ mod test {
         let small_board = "#.##\n#<>#\n#^v#\n#..#\n##.#";
         let board: Board = small_board.parse().unwrap();
 
-        let wall_left = board.at(&Coordinate::new(3, 1), 0);
+        let wall_left = board.at(&Point::new(3, 1), 0);
 
         assert_eq!(wall_left, Space::Wall);
     }
@@ -359,9 +354,9 @@ mod test {
         let small_board = "#.##\n#<>#\n#^v#\n#..#\n##.#";
         let board: Board = small_board.parse().unwrap();
 
-        let wall_left = board.at(&Coordinate::new(1, 1), 0);
+        let wall_left = board.at(&Point::new(1, 1), 0);
 
-        assert_eq!(wall_left, Space::Blizzard(Direction::Unknown));
+        assert_eq!(wall_left, Space::AnyBlizzard);
     }
 
     #[test]
@@ -369,7 +364,7 @@ mod test {
         let small_board = "#.##\n#<>#\n#^v#\n#..#\n##.#";
         let board: Board = small_board.parse().unwrap();
 
-        let wall_left = board.at(&Coordinate::new(2, 2), 1);
+        let wall_left = board.at(&Point::new(2, 2), 1);
 
         assert_eq!(wall_left, Space::Empty);
     }
@@ -379,9 +374,9 @@ mod test {
         let small_board = "#.##\n#<>#\n#^v#\n#..#\n##.#";
         let board: Board = small_board.parse().unwrap();
 
-        let wall_left = board.at(&Coordinate::new(2, 3), 1);
+        let wall_left = board.at(&Point::new(2, 3), 1);
 
-        assert_eq!(wall_left, Space::Blizzard(Direction::Unknown));
+        assert_eq!(wall_left, Space::AnyBlizzard);
     }
 
     #[test]
@@ -389,9 +384,16 @@ mod test {
         let small_board = "#.##\n#<>#\n#^v#\n#..#\n##.#";
         let board: Board = small_board.parse().unwrap();
 
-        let wall_left = board.at(&Coordinate::new(1, 3), 2);
+        let wall_left = board.at(&Point::new(1, 3), 2);
+
+        assert_eq!(wall_left, Space::AnyBlizzard);
+    }
+
+    #[test]
+    fn bad_space_is_located() {
+        let error = "#.##\n#<?#\n##.#".parse::<Board>().unwrap_err();
 
-        assert_eq!(wall_left, Space::Blizzard(Direction::Unknown));
+        assert_eq!(error, ParseError::new(ValleyError::NotASpace, 2, 3, "?"));
     }
 }
 
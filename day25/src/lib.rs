@@ -0,0 +1,149 @@
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{Diagnostic, ParseError, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?)
+    }
+    fn part_2(&self, _input: &str) -> Result<String> {
+        Err(anyhow!("day 25 only has one puzzle"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnafuError {
+    NotADigit,
+}
+
+impl fmt::Display for SnafuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnafuError::NotADigit => write!(f, "expected one of `2`, `1`, `0`, `-` or `=`"),
+        }
+    }
+}
+
+pub fn part_1(input: &str) -> Result<String, ParseError<SnafuError>> {
+    let mut digit = 0;
+    for (index, line) in input.lines().enumerate() {
+        digit += snafu_to_digit(line).map_err(|error| error.offset_lines(index))?;
+    }
+    Ok(digit_to_snafu(digit))
+}
+
+fn digit_to_snafu(mut val: i64) -> String {
+    let mut snafu: Vec<char> = vec![];
+    while val > 0 {
+        let power = 5i64;
+        let remainder = val % power;
+        val -= remainder;
+        let char = match remainder {
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            3 => {
+                val += 6;
+                '='
+            }
+            4 => {
+                val += 5;
+                '-'
+            }
+            _ => unreachable!(),
+        };
+        val /= 5;
+        snafu.push(char);
+    }
+    snafu.into_iter().rev().collect()
+}
+
+fn snafu_to_digit(snafu: &str) -> Result<i64, ParseError<SnafuError>> {
+    snafu
+        .char_indices()
+        .rev()
+        .enumerate()
+        .try_fold(0, |acc, (i, (offset, c))| {
+            let digit = match c {
+                '0' => 0,
+                '1' => 1,
+                '2' => 2,
+                '-' => -1,
+                '=' => -2,
+                _ => {
+                    let text = &snafu[offset..offset + c.len_utf8()];
+                    return Err(ParseError::at(SnafuError::NotADigit, snafu, text));
+                }
+            };
+            Ok(acc + 5i64.pow(i as u32) * digit)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn digit_10_to_snafu() {
+        let input = 10;
+
+        let snafu = digit_to_snafu(input);
+
+        assert_eq!(snafu, String::from("20"))
+    }
+
+    #[test]
+    fn snafu_2minus_to_digit() {
+        let input = "2-";
+
+        let digit = snafu_to_digit(input).unwrap();
+
+        assert_eq!(digit, 9);
+    }
+
+    #[test]
+    fn snafu_20_to_digit() {
+        let input = "20";
+
+        let digit = snafu_to_digit(input).unwrap();
+
+        assert_eq!(digit, 10);
+    }
+
+    #[test]
+    fn long_snafu_to_digit() {
+        let input = "1121-1110-1=0";
+
+        let digit = snafu_to_digit(input).unwrap();
+
+        assert_eq!(digit, 314159265);
+    }
+
+    #[test]
+    fn long_digit_to_snafu() {
+        let input = 314159265;
+
+        let snafu = digit_to_snafu(input);
+
+        assert_eq!(snafu, "1121-1110-1=0");
+    }
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day25/dev.txt");
+
+        let output = part_1(input).unwrap();
+
+        assert_eq!(output, String::from("2=-1=0"));
+    }
+
+    #[test]
+    fn bad_digit_is_located() {
+        let error = part_1("1=\n2-3\n").unwrap_err();
+
+        assert_eq!(error, ParseError::new(SnafuError::NotADigit, 2, 3, "3"));
+    }
+}
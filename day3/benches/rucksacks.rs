@@ -0,0 +1,35 @@
+//! Compares the bitset rucksacks with the original `Vec<char>` scans on the
+//! prod input. Run with `cargo bench -p day3 --features bench --bench rucksacks`.
+
+use aoc_common::input_path;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::fs;
+
+fn bench_rucksacks(c: &mut Criterion) {
+    let input = fs::read_to_string(input_path(3, "prod.txt")).expect("day 3 has a prod input");
+    let mut group = c.benchmark_group("day03-rucksacks");
+    group.bench_with_input(
+        BenchmarkId::new("part1", "vec"),
+        input.as_str(),
+        |b, input| b.iter(|| day3::naive::part_1(input)),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("part1", "bitset"),
+        input.as_str(),
+        |b, input| b.iter(|| day3::part_1(input)),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("part2", "vec"),
+        input.as_str(),
+        |b, input| b.iter(|| day3::naive::part_2(input)),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("part2", "bitset"),
+        input.as_str(),
+        |b, input| b.iter(|| day3::part_2(input)),
+    );
+    group.finish();
+}
+
+criterion_group!(benches, bench_rucksacks);
+criterion_main!(benches);
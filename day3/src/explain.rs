@@ -0,0 +1,166 @@
+use crate::{shared_items, ItemSet, Rucksack, RucksackError};
+use aoc_common::ParseError;
+use serde::Serialize;
+use std::fmt;
+
+/// Something about a rucksack or group that stops it having a priority.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Finding {
+    NoSharedItem,
+    SeveralSharedItems,
+    IncompleteGroup,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::NoSharedItem => write!(f, "no_shared_item"),
+            Finding::SeveralSharedItems => write!(f, "several_shared_items"),
+            Finding::IncompleteGroup => write!(f, "incomplete_group"),
+        }
+    }
+}
+
+/// Why one rucksack scores what it does in part 1.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SackExplanation {
+    pub line: usize,
+    pub compartments: [String; 2],
+    pub shared: String,
+    pub priority: Option<u32>,
+    pub finding: Option<Finding>,
+}
+
+/// Why one group scores what it does in part 2.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct GroupExplanation {
+    pub lines: Vec<usize>,
+    pub rucksacks: Vec<String>,
+    pub shared: String,
+    pub priority: Option<u32>,
+    pub finding: Option<Finding>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    pub rucksacks: Vec<SackExplanation>,
+    pub groups: Vec<GroupExplanation>,
+}
+
+impl Explanation {
+    /// The part 1 and part 2 totals over everything that has a priority.
+    pub fn totals(&self) -> (u32, u32) {
+        (
+            self.rucksacks.iter().filter_map(|sack| sack.priority).sum(),
+            self.groups.iter().filter_map(|group| group.priority).sum(),
+        )
+    }
+
+    /// Every rucksack and group with a finding, as `(first line, finding)`.
+    pub fn findings(&self) -> Vec<(usize, Finding)> {
+        let sacks = self
+            .rucksacks
+            .iter()
+            .filter_map(|sack| Some((sack.line, sack.finding?)));
+        let groups = self
+            .groups
+            .iter()
+            .filter_map(|group| Some((group.lines[0], group.finding?)));
+        sacks.chain(groups).collect()
+    }
+
+    /// One row per rucksack, then one per group. Compartments and the
+    /// rucksacks in a group are separated by spaces, which items never are.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("kind,line,contents,shared,priority,finding\n");
+        let optional = |value: Option<String>| value.unwrap_or_default();
+        for sack in &self.rucksacks {
+            csv.push_str(&format!(
+                "rucksack,{},{},{},{},{}\n",
+                sack.line,
+                sack.compartments.join(" "),
+                sack.shared,
+                optional(sack.priority.map(|p| p.to_string())),
+                optional(sack.finding.map(|f| f.to_string())),
+            ));
+        }
+        for group in &self.groups {
+            csv.push_str(&format!(
+                "group,{},{},{},{},{}\n",
+                group.lines[0],
+                group.rucksacks.join(" "),
+                group.shared,
+                optional(group.priority.map(|p| p.to_string())),
+                optional(group.finding.map(|f| f.to_string())),
+            ));
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("explanations are plain data")
+    }
+}
+
+/// Explains both parts, in groups of `group_size`. Only lines that are not
+/// rucksacks at all are errors; anything else that would stop a part being
+/// solved is a finding.
+///
+/// Panics if `group_size` is 0.
+pub fn explain(input: &str, group_size: usize) -> Result<Explanation, ParseError<RucksackError>> {
+    let mut sacks: Vec<(usize, &str, Rucksack)> = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let sack = line
+            .parse()
+            .map_err(|error: ParseError<RucksackError>| error.offset_lines(index))?;
+        sacks.push((index + 1, line, sack));
+    }
+
+    let rucksacks = sacks
+        .iter()
+        .map(|&(line, text, sack)| {
+            let (left, right) = text.split_at(text.len() / 2);
+            let (priority, finding) = judge(sack.shared_items());
+            SackExplanation {
+                line,
+                compartments: [left.to_string(), right.to_string()],
+                shared: sack.shared_items().items().collect(),
+                priority,
+                finding,
+            }
+        })
+        .collect();
+
+    let groups = sacks
+        .chunks(group_size)
+        .map(|group| {
+            let shared = shared_items(group.iter().map(|(_, _, sack)| sack));
+            let (priority, finding) = if group.len() < group_size {
+                (None, Some(Finding::IncompleteGroup))
+            } else {
+                judge(shared)
+            };
+            GroupExplanation {
+                lines: group.iter().map(|&(line, _, _)| line).collect(),
+                rucksacks: group.iter().map(|(_, text, _)| text.to_string()).collect(),
+                shared: shared.items().collect(),
+                priority,
+                finding,
+            }
+        })
+        .collect();
+
+    Ok(Explanation { rucksacks, groups })
+}
+
+fn judge(shared: ItemSet) -> (Option<u32>, Option<Finding>) {
+    match shared.len() {
+        0 => (None, Some(Finding::NoSharedItem)),
+        1 => (Some(shared.priority_sum()), None),
+        _ => (None, Some(Finding::SeveralSharedItems)),
+    }
+}
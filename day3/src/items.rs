@@ -0,0 +1,109 @@
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+pub type Item = char;
+
+/// The priority of an item: `a` to `z` are 1 to 26 and `A` to `Z` are 27 to
+/// 52. Anything else has none.
+pub fn priority(item: Item) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+fn item_with_priority(priority: u32) -> Item {
+    match priority {
+        1..=26 => char::from(b'a' + (priority - 1) as u8),
+        _ => char::from(b'A' + (priority - 27) as u8),
+    }
+}
+
+/// A set of items, as one bit per priority, so sets intersect with a single
+/// AND.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    /// Returns `None` if `items` holds anything that is not an item.
+    pub fn from_items(items: &str) -> Option<Self> {
+        let mut set = ItemSet::EMPTY;
+        for item in items.chars() {
+            set.insert(item)?;
+        }
+        Some(set)
+    }
+
+    /// Returns `None` if `item` is not an item.
+    pub fn insert(&mut self, item: Item) -> Option<()> {
+        self.0 |= 1 << (priority(item)? - 1);
+        Some(())
+    }
+
+    pub fn contains(&self, item: Item) -> bool {
+        priority(item).is_some_and(|priority| self.0 & 1 << (priority - 1) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The item, if the set holds exactly one.
+    pub fn single(&self) -> Option<Item> {
+        (self.len() == 1).then(|| item_with_priority(self.0.trailing_zeros() + 1))
+    }
+
+    /// The items in order of priority.
+    pub fn items(&self) -> impl Iterator<Item = Item> {
+        let bits = self.0;
+        (0..52)
+            .filter(move |bit| bits & 1 << bit != 0)
+            .map(|bit| item_with_priority(bit + 1))
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        self.items().filter_map(priority).sum()
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+
+impl BitAndAssign for ItemSet {
+    fn bitand_assign(&mut self, other: ItemSet) {
+        self.0 &= other.0;
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for ItemSet {
+    fn bitor_assign(&mut self, other: ItemSet) {
+        self.0 |= other.0;
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.items()).finish()
+    }
+}
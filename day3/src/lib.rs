@@ -0,0 +1,292 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+mod explain;
+mod items;
+#[cfg(any(test, feature = "bench"))]
+pub mod naive;
+pub use explain::*;
+pub use items::*;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+pub fn part_1(input: &str) -> Result<u32, ParseError<RucksackError>> {
+    parse_rucksacks(input)?
+        .into_iter()
+        .map(|(line, sack)| only_item(sack.shared_items(), input, line))
+        .sum()
+}
+
+pub fn part_2(input: &str) -> Result<u32, ParseError<RucksackError>> {
+    sum_badge_priorities(input, 3)
+}
+
+/// Splits the rucksacks into groups of `size` and adds up the priority of
+/// the one item each group shares.
+///
+/// Panics if `size` is 0.
+pub fn sum_badge_priorities(input: &str, size: usize) -> Result<u32, ParseError<RucksackError>> {
+    let rucksacks = parse_rucksacks(input)?;
+    let groups = rucksacks.chunks(size);
+    if let Some(group) = groups.clone().last().filter(|group| group.len() < size) {
+        return Err(ParseError::at(
+            RucksackError::IncompleteGroup,
+            input,
+            group[0].0,
+        ));
+    }
+    groups
+        .map(|group| {
+            let sacks = group.iter().map(|(_, sack)| sack);
+            only_item(shared_items(sacks), input, group[0].0)
+        })
+        .sum()
+}
+
+fn only_item(items: ItemSet, input: &str, line: &str) -> Result<u32, ParseError<RucksackError>> {
+    match items.len() {
+        0 => Err(ParseError::at(RucksackError::NoSharedItem, input, line)),
+        1 => Ok(items.priority_sum()),
+        _ => Err(ParseError::at(
+            RucksackError::SeveralSharedItems,
+            input,
+            line,
+        )),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RucksackError {
+    NotAnItem,
+    OddLength,
+    NoSharedItem,
+    SeveralSharedItems,
+    IncompleteGroup,
+}
+
+impl fmt::Display for RucksackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RucksackError::NotAnItem => write!(f, "items must be letters"),
+            RucksackError::OddLength => {
+                write!(f, "rucksack cannot be split into two equal compartments")
+            }
+            RucksackError::NoSharedItem => write!(f, "no item is shared"),
+            RucksackError::SeveralSharedItems => write!(f, "more than one item is shared"),
+            RucksackError::IncompleteGroup => {
+                write!(f, "group starting here has too few rucksacks")
+            }
+        }
+    }
+}
+
+/// Every non-empty line as a rucksack, alongside the line it came from.
+pub fn parse_rucksacks(input: &str) -> Result<Vec<(&str, Rucksack)>, ParseError<RucksackError>> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.parse()
+                .map(|sack| (line, sack))
+                .map_err(|error: ParseError<RucksackError>| error.within(input, line))
+        })
+        .collect()
+}
+
+/// The items every one of `sacks` holds. No sacks share nothing.
+pub fn shared_items<'a>(sacks: impl IntoIterator<Item = &'a Rucksack>) -> ItemSet {
+    let mut sacks = sacks.into_iter();
+    let Some(first) = sacks.next() else {
+        return ItemSet::EMPTY;
+    };
+    sacks.fold(first.contents(), |shared, sack| shared & sack.contents())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    left: ItemSet,
+    right: ItemSet,
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError<RucksackError>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if let Some((offset, item)) = input
+            .char_indices()
+            .find(|&(_, item)| priority(item).is_none())
+        {
+            let text = &input[offset..offset + item.len_utf8()];
+            return Err(ParseError::at(RucksackError::NotAnItem, input, text));
+        }
+        if !input.len().is_multiple_of(2) {
+            return Err(ParseError::at(RucksackError::OddLength, input, input));
+        }
+        let (left, right) = input.split_at(input.len() / 2);
+        Ok(Rucksack {
+            left: ItemSet::from_items(left).expect("items were checked"),
+            right: ItemSet::from_items(right).expect("items were checked"),
+        })
+    }
+}
+
+impl Rucksack {
+    pub fn left(&self) -> ItemSet {
+        self.left
+    }
+
+    pub fn right(&self) -> ItemSet {
+        self.right
+    }
+
+    pub fn contents(&self) -> ItemSet {
+        self.left | self.right
+    }
+
+    /// The items in both compartments.
+    pub fn shared_items(&self) -> ItemSet {
+        self.left & self.right
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    const GIVEN: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn given() {
+        assert_eq!(part_1(GIVEN).unwrap(), 157);
+        assert_eq!(part_2(GIVEN).unwrap(), 70);
+    }
+
+    #[test]
+    fn bad_item_is_located() {
+        let error = part_1("vJrwpWtwJgWrhcsFMMfFFhFp\nabc1ef").unwrap_err();
+
+        assert_eq!(error, ParseError::new(RucksackError::NotAnItem, 2, 4, "1"));
+    }
+
+    #[test]
+    fn item_sets_intersect() {
+        let left = ItemSet::from_items("vJrwpWtwJgWr").unwrap();
+        let right = ItemSet::from_items("hcsFMMfFFhFp").unwrap();
+
+        assert_eq!(left.len(), 8);
+        assert!(left.contains('J') && !left.contains('j'));
+        assert_eq!((left & right).single(), Some('p'));
+        assert_eq!(ItemSet::ALL.items().count(), 52);
+        assert_eq!(ItemSet::from_items("aZ").unwrap().priority_sum(), 1 + 52);
+        assert_eq!(ItemSet::from_items("a1"), None);
+    }
+
+    #[test]
+    fn all_shared_items_are_found() {
+        let sack: Rucksack = "abcXabcY".parse().unwrap();
+
+        assert_eq!(sack.shared_items().items().collect::<String>(), "abc");
+        assert_eq!(
+            part_1("abcXabcY").unwrap_err(),
+            ParseError::new(RucksackError::SeveralSharedItems, 1, 1, "abcXabcY")
+        );
+    }
+
+    #[test]
+    fn groups_can_be_any_size() {
+        let lines: Vec<&str> = GIVEN.lines().collect();
+
+        assert_eq!(sum_badge_priorities(GIVEN, 3).unwrap(), 70);
+        let pairs = sum_badge_priorities(GIVEN, 2).unwrap_err();
+        assert_eq!(
+            pairs,
+            ParseError::new(RucksackError::SeveralSharedItems, 1, 1, lines[0])
+        );
+        assert_eq!(
+            sum_badge_priorities(GIVEN, 4).unwrap_err().kind,
+            RucksackError::IncompleteGroup
+        );
+    }
+
+    #[test]
+    fn bitset_matches_naive() {
+        let input = include_str!("../../inputs/day03/prod.txt");
+
+        assert_eq!(part_1(input).unwrap(), naive::part_1(input));
+        assert_eq!(part_2(input).unwrap(), naive::part_2(input));
+    }
+
+    #[test]
+    fn explains_each_priority() {
+        let explanation = explain(GIVEN, 3).unwrap();
+
+        assert_eq!(explanation.totals(), (157, 70));
+        assert_eq!(
+            explanation.rucksacks[1],
+            SackExplanation {
+                line: 2,
+                compartments: [
+                    "jqHRNqRjqzjGDLGL".to_string(),
+                    "rsFMfFZSrLrFZsSL".to_string()
+                ],
+                shared: "L".to_string(),
+                priority: Some(38),
+                finding: None,
+            }
+        );
+        assert_eq!(explanation.groups[1].lines, vec![4, 5, 6]);
+        assert_eq!(explanation.groups[1].shared, "Z");
+    }
+
+    #[test]
+    fn bad_sacks_are_findings() {
+        let explanation = explain("abcXabcY\n\nabcdefgh\nab", 2).unwrap();
+
+        assert_eq!(
+            explanation.findings(),
+            vec![
+                (1, Finding::SeveralSharedItems),
+                (3, Finding::NoSharedItem),
+                (4, Finding::NoSharedItem),
+                (1, Finding::SeveralSharedItems),
+                (4, Finding::IncompleteGroup),
+            ]
+        );
+        assert_eq!(
+            explanation.to_csv(),
+            "kind,line,contents,shared,priority,finding\n\
+             rucksack,1,abcX abcY,abc,,several_shared_items\n\
+             rucksack,3,abcd efgh,,,no_shared_item\n\
+             rucksack,4,a b,,,no_shared_item\n\
+             group,1,abcXabcY abcdefgh,abc,,several_shared_items\n\
+             group,4,ab,ab,,incomplete_group\n"
+        );
+    }
+
+    #[test]
+    fn explanations_export_as_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&explain("vJrwpWtwJgWrhcsFMMfFFhFp", 1).unwrap().to_json())
+                .unwrap();
+
+        assert_eq!(json["rucksacks"][0]["shared"], "p");
+        assert_eq!(json["rucksacks"][0]["priority"], 16);
+        assert_eq!(json["groups"][0]["finding"], "several_shared_items");
+    }
+}
@@ -0,0 +1,48 @@
+//! The original `Vec<char>` implementation, kept as a baseline for
+//! `benches/rucksacks.rs`. It assumes the input is valid and panics when a
+//! rucksack or group shares no item. Only built for tests and with the
+//! `bench` feature.
+
+use itertools::Itertools;
+
+pub fn part_1(input: &str) -> u32 {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (left, right) = line.split_at(line.len() / 2);
+            let left: Vec<char> = left.chars().collect();
+            let right: Vec<char> = right.chars().collect();
+            let shared = left
+                .into_iter()
+                .find(|item| right.contains(item))
+                .expect("Cannot Find shared item!");
+            get_priority_for_item(shared)
+        })
+        .sum()
+}
+
+pub fn part_2(input: &str) -> u32 {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .tuples()
+        .map(|(sack1, sack2, sack3)| {
+            let shared = sack1
+                .into_iter()
+                .find(|item| sack2.contains(item) && sack3.contains(item))
+                .expect("Cannot Find shared item!");
+            get_priority_for_item(shared)
+        })
+        .sum()
+}
+
+fn get_priority_for_item(item: char) -> u32 {
+    let num = item as u32;
+    if num > 64 && num < 91 {
+        num - 38
+    } else {
+        num - 96
+    }
+}
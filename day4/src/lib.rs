@@ -1,21 +1,32 @@
-use anyhow::anyhow;
-use std::{fs, str::FromStr};
-
-fn main() {
-    println!("Hello, world!");
-    let input = include_str!("./input.txt");
-    let count = part_1(&input);
-    println!("Part 1: {:?}", count);
+use anyhow::Result;
+use aoc_common::{Interval, ParseError, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+mod report;
+pub use report::*;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).to_string())
+    }
 }
 
-struct RangePair {
-    first: Range,
-    second: Range,
+/// The sections assigned to each elf in a pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangePair {
+    pub first: Interval,
+    pub second: Interval,
 }
 
 impl RangePair {
-    fn fully_overlaps(&self) -> bool {
-        self.first.overlaps(&self.second) || self.second.overlaps(&self.first)
+    pub fn fully_overlaps(&self) -> bool {
+        self.first.contains_interval(&self.second) || self.second.contains_interval(&self.first)
     }
     fn fully_overlaps_score(&self) -> usize {
         match self.fully_overlaps() {
@@ -23,126 +34,114 @@ impl RangePair {
             false => 0,
         }
     }
-    fn partially_overlaps(&self) -> bool {
-        self.first.partially_overlaps(&self.second) || self.second.partially_overlaps(&self.first)
-    }
-}
-
-impl FromStr for RangePair {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (o, p) = match s.split_once(",") {
-            Some((o, p)) => (o, p),
-            None => return Err(anyhow::anyhow!("invalid input")),
-        };
-        let first = str::parse::<Range>(o)?;
-        let second = str::parse::<Range>(p)?;
-        Ok(Self { first, second })
+    pub fn partially_overlaps(&self) -> bool {
+        self.first.overlaps(&self.second)
     }
 }
 
-struct Range {
-    left: i32,
-    right: i32,
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssignmentError {
+    MissingComma,
+    MissingDash,
+    NotANumber,
+    Backwards,
 }
 
-impl Range {
-    fn overlaps(&self, other: &Self) -> bool {
-        return (self.right >= other.right && self.left <= other.left)
-            || (other.right >= self.right && other.left <= self.left);
+impl fmt::Display for AssignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignmentError::MissingComma => write!(f, "expected two ranges separated by ','"),
+            AssignmentError::MissingDash => write!(f, "expected a range like 2-4"),
+            AssignmentError::NotANumber => write!(f, "section id is not a number"),
+            AssignmentError::Backwards => write!(f, "range ends before it starts"),
+        }
     }
 }
 
-impl FromStr for Range {
-    type Err = anyhow::Error;
-
+impl FromStr for RangePair {
+    type Err = ParseError<AssignmentError>;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = match s.split_once('-') {
-            Some((l, r)) => (l, r),
-            None => return Err(anyhow::anyhow!("invalid input")),
-        };
-        let left = match str::parse::<i32>(left) {
-            Ok(value) => value,
-            Err(_) => return Err(anyhow::anyhow!("invalid input")),
-        };
-        let right = match str::parse::<i32>(right) {
-            Ok(value) => value,
-            Err(_) => return Err(anyhow::anyhow!("invalid input")),
-        };
-        Ok(Self { left, right })
+        let (o, p) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(AssignmentError::MissingComma, s, s))?;
+        let first = parse_range(o).map_err(|error| error.within(s, o))?;
+        let second = parse_range(p).map_err(|error| error.within(s, p))?;
+        Ok(Self { first, second })
     }
 }
 
-pub fn part_1(input: &'static str) -> usize {
-    let count = input
-        .lines()
-        .flat_map(|line| line.parse::<RangePair>())
+/// Parses a range of sections like `2-4`.
+pub fn parse_range(s: &str) -> Result<Interval, ParseError<AssignmentError>> {
+    let (left, right) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(AssignmentError::MissingDash, s, s))?;
+    let section = |id: &str| {
+        str::parse::<i64>(id).map_err(|_| ParseError::at(AssignmentError::NotANumber, s, id))
+    };
+    Interval::new(section(left)?, section(right)?)
+        .ok_or_else(|| ParseError::at(AssignmentError::Backwards, s, s))
+}
+
+pub fn part_1(input: &str) -> usize {
+    parse_to_range_pairs(input)
         .filter(|pair| pair.fully_overlaps())
-        .count();
-    return count;
+        .count()
 }
 
-pub fn part_1_map(input: &'static str) -> usize {
+pub fn part_1_map(input: &str) -> usize {
     let count = parse_to_range_pairs(input)
         .map(|pair| pair.fully_overlaps_score())
         .sum();
-    return count;
+    count
 }
 
-fn part_2(input: &'static str) -> usize {
-    let count = parse_to_range_pairs(input)
+pub fn part_2(input: &str) -> usize {
+    parse_to_range_pairs(input)
         .filter(|pair| pair.partially_overlaps())
-        .count();
-    return count;
+        .count()
 }
 
-fn parse_to_range_pairs(input: &'static str) -> impl Iterator<Item = RangePair> {
+/// Every line that is a valid pair of ranges. Other lines are skipped.
+pub fn parse_to_range_pairs(input: &str) -> impl Iterator<Item = RangePair> + '_ {
     input.lines().flat_map(|line| line.parse::<RangePair>())
 }
 
 #[cfg(test)]
 mod test {
     use crate::*;
-    use std::time::Instant;
 
     #[test]
     fn aoc_solution_1() {
-        let input = include_str!("./input.txt");
+        let input = include_str!("../../inputs/day04/prod.txt");
 
-        let count = part_1(&input);
+        let count = part_1(input);
 
         assert_eq!(count, 441);
     }
 
     #[test]
     fn aoc_solution_filter() {
-        let input = include_str!("./input.txt");
-        let now = Instant::now();
+        let input = include_str!("../../inputs/day04/prod.txt");
 
-        let count = part_1(&input);
-        let elapsed = now.elapsed();
-        println!("Elapsed (filter) : {:.2?}", elapsed);
+        let count = part_1(input);
 
         assert_eq!(count, 441);
     }
 
     #[test]
     fn aoc_solution_map() {
-        let input = include_str!("./input.txt");
-        let now = Instant::now();
+        let input = include_str!("../../inputs/day04/prod.txt");
 
-        let count = part_1_map(&input);
-        let elapsed = now.elapsed();
-        println!("Elapsed (map) : {:.2?}", elapsed);
+        let count = part_1_map(input);
 
         assert_eq!(count, 441);
     }
 
     #[test]
     fn aoc_solution_2() {
-        let input = include_str!("./input.txt");
+        let input = include_str!("../../inputs/day04/prod.txt");
 
-        let count = part_2(&input);
+        let count = part_2(input);
 
         assert_eq!(count, 861);
     }
@@ -155,4 +154,155 @@ mod test {
 
         assert_eq!(count, 1);
     }
+
+    #[test]
+    fn invalid_range_is_located() {
+        let error = "1-20,2.2-13".parse::<RangePair>().unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(AssignmentError::NotANumber, 1, 6, "2.2")
+        );
+    }
+
+    #[test]
+    fn given() {
+        let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+
+        assert_eq!(part_1(input), 2);
+        assert_eq!(part_2(input), 4);
+    }
+
+    #[test]
+    fn backwards_range_is_located() {
+        let error = "1-2,9-3".parse::<RangePair>().unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(AssignmentError::Backwards, 1, 5, "9-3")
+        );
+    }
+
+    #[test]
+    fn overlap_kinds() {
+        let kinds: Vec<_> = parse_to_range_pairs("2-4,6-8\n5-7,7-9\n3-7,2-8\n2-8,3-7\n4-6,4-6")
+            .map(|pair| pair.overlap_kind())
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                OverlapKind::Disjoint,
+                OverlapKind::Partial,
+                OverlapKind::NestedLeft,
+                OverlapKind::NestedRight,
+                OverlapKind::Identical
+            ]
+        );
+    }
+
+    #[test]
+    fn conflict_report_totals_waste_and_coverage() {
+        let report = conflict_report("2-4,6-8\n5-7,7-9\n2-3,3-3");
+
+        assert_eq!(
+            report.pairs[1],
+            PairConflict {
+                pair: 2,
+                first: Sections { start: 5, end: 7 },
+                second: Sections { start: 7, end: 9 },
+                kind: OverlapKind::Partial,
+                overlap: Some(Sections { start: 7, end: 7 }),
+                wasted: 1,
+            }
+        );
+        assert_eq!(report.wasted, 2);
+        let elves: Vec<_> = report
+            .coverage
+            .iter()
+            .map(|c| (c.start, c.end, c.elves))
+            .collect();
+        assert_eq!(
+            elves,
+            vec![
+                (2, 2, 2),
+                (3, 3, 3),
+                (4, 5, 1),
+                (6, 6, 2),
+                (7, 7, 3),
+                (8, 8, 2),
+                (9, 9, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn coverage_of_wide_ranges_is_run_length() {
+        let report = conflict_report("1-4000000000,1-1\n10-20,30-9223372036854775807");
+
+        let elves: Vec<_> = report
+            .coverage
+            .iter()
+            .map(|c| (c.start, c.end, c.elves))
+            .collect();
+        assert_eq!(
+            elves,
+            vec![
+                (1, 1, 2),
+                (2, 9, 1),
+                (10, 20, 2),
+                (21, 29, 1),
+                (30, 4_000_000_000, 2),
+                (4_000_000_001, i64::MAX, 1)
+            ]
+        );
+        assert_eq!(report.to_text().lines().count(), 3 + 4 + 6);
+    }
+
+    #[test]
+    fn wasted_sections_of_full_width_ranges_do_not_overflow() {
+        let pair = "0-9223372036854775807,0-9223372036854775807";
+        let report = conflict_report(&format!("{pair}\n{pair}"));
+
+        assert_eq!(report.pairs[0].wasted, 1 << 63);
+        assert_eq!(report.wasted, 1 << 64);
+    }
+
+    #[test]
+    fn coverage_includes_unassigned_gaps() {
+        let report = conflict_report("1-2,8-9");
+
+        let elves: Vec<_> = report
+            .coverage
+            .iter()
+            .map(|c| (c.start, c.end, c.elves))
+            .collect();
+        assert_eq!(elves, vec![(1, 2, 1), (3, 7, 0), (8, 9, 1)]);
+    }
+
+    #[test]
+    fn conflict_report_renders() {
+        let report = conflict_report("2-4,6-8\n3-7,2-8");
+
+        assert_eq!(
+            report.to_text(),
+            " pair  first     second    kind          overlap   wasted
+    1  2-4       6-8       disjoint      -              0
+    2  3-7       2-8       nested left   3-7            5
+
+5 sections wasted
+
+coverage:
+2-2    2
+3-4    3
+5-5    2
+6-7    3
+8-8    2
+"
+        );
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["pairs"][1]["kind"], "nested_left");
+        assert_eq!(json["pairs"][0]["overlap"], serde_json::Value::Null);
+        assert_eq!(json["coverage"][0]["elves"], 2);
+    }
 }
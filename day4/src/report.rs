@@ -0,0 +1,207 @@
+use crate::{parse_to_range_pairs, RangePair};
+use aoc_common::Interval;
+use serde::Serialize;
+use std::fmt::{self, Write};
+
+/// How the two elves' assignments in a pair relate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlapKind {
+    Disjoint,
+    Partial,
+    /// The first elf's sections all lie within the second's.
+    NestedLeft,
+    /// The second elf's sections all lie within the first's.
+    NestedRight,
+    Identical,
+}
+
+impl fmt::Display for OverlapKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OverlapKind::Disjoint => "disjoint",
+            OverlapKind::Partial => "partial",
+            OverlapKind::NestedLeft => "nested left",
+            OverlapKind::NestedRight => "nested right",
+            OverlapKind::Identical => "identical",
+        };
+        f.pad(name)
+    }
+}
+
+impl RangePair {
+    pub fn overlap_kind(&self) -> OverlapKind {
+        let (first, second) = (self.first, self.second);
+        if first == second {
+            OverlapKind::Identical
+        } else if second.contains_interval(&first) {
+            OverlapKind::NestedLeft
+        } else if first.contains_interval(&second) {
+            OverlapKind::NestedRight
+        } else if first.overlaps(&second) {
+            OverlapKind::Partial
+        } else {
+            OverlapKind::Disjoint
+        }
+    }
+
+    /// The sections both elves were given.
+    pub fn overlap(&self) -> Option<Interval> {
+        self.first.intersection(&self.second)
+    }
+}
+
+/// An inclusive run of section IDs, as it appears in a report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Sections {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl From<Interval> for Sections {
+    fn from(interval: Interval) -> Self {
+        Sections {
+            start: interval.start(),
+            end: interval.end(),
+        }
+    }
+}
+
+impl From<Coverage> for Sections {
+    fn from(coverage: Coverage) -> Self {
+        Sections {
+            start: coverage.start,
+            end: coverage.end,
+        }
+    }
+}
+
+impl fmt::Display for Sections {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{}-{}", self.start, self.end))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PairConflict {
+    /// Which pair this is, counting from 1.
+    pub pair: usize,
+    pub first: Sections,
+    pub second: Sections,
+    pub kind: OverlapKind,
+    pub overlap: Option<Sections>,
+    /// Sections cleaned twice because both elves were given them.
+    pub wasted: i128,
+}
+
+/// How many elves were given each section in a run of sections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Coverage {
+    pub start: i64,
+    pub end: i64,
+    pub elves: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ConflictReport {
+    pub pairs: Vec<PairConflict>,
+    pub wasted: i128,
+    /// Runs of sections given to the same number of elves, from the lowest
+    /// section assigned to the highest, including any nobody was given.
+    pub coverage: Vec<Coverage>,
+}
+
+/// Reports on every valid pair in the input.
+pub fn conflict_report(input: &str) -> ConflictReport {
+    let pairs: Vec<PairConflict> = parse_to_range_pairs(input)
+        .enumerate()
+        .map(|(index, pair)| {
+            let overlap = pair.overlap();
+            PairConflict {
+                pair: index + 1,
+                first: pair.first.into(),
+                second: pair.second.into(),
+                kind: pair.overlap_kind(),
+                overlap: overlap.map(Sections::from),
+                wasted: overlap.map_or(0, |overlap| overlap.len()),
+            }
+        })
+        .collect();
+    let wasted = pairs.iter().map(|pair| pair.wasted).sum();
+    let coverage = coverage(pairs.iter().flat_map(|pair| [pair.first, pair.second]));
+    ConflictReport {
+        pairs,
+        wasted,
+        coverage,
+    }
+}
+
+/// Counts the elves on each section by sorting where assignments start and
+/// stop and sweeping over them, so the cost depends on how many assignments
+/// there are rather than how many sections they span.
+fn coverage(assignments: impl Iterator<Item = Sections>) -> Vec<Coverage> {
+    let mut changes: Vec<(i64, isize)> = Vec::new();
+    for sections in assignments {
+        changes.push((sections.start, 1));
+        // An assignment reaching i64::MAX never stops within the report.
+        if let Some(after) = sections.end.checked_add(1) {
+            changes.push((after, -1));
+        }
+    }
+    changes.sort_unstable();
+
+    let mut runs: Vec<Coverage> = Vec::new();
+    let mut elves = 0isize;
+    for (index, &(section, change)) in changes.iter().enumerate() {
+        elves += change;
+        let end = match changes.get(index + 1) {
+            Some(&(next, _)) if next == section => continue,
+            Some(&(next, _)) => next - 1,
+            None if elves > 0 => i64::MAX,
+            None => break,
+        };
+        let elves = elves as usize;
+        match runs.last_mut() {
+            Some(last) if last.elves == elves => last.end = end,
+            _ => runs.push(Coverage {
+                start: section,
+                end,
+                elves,
+            }),
+        }
+    }
+    runs
+}
+
+impl ConflictReport {
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{:>5}  {:<9} {:<9} {:<13} {:<9} {:>6}\n",
+            "pair", "first", "second", "kind", "overlap", "wasted"
+        );
+        for pair in &self.pairs {
+            let overlap = pair.overlap.map_or("-".to_string(), |o| o.to_string());
+            writeln!(
+                text,
+                "{:>5}  {:<9} {:<9} {:<13} {:<9} {:>6}",
+                pair.pair, pair.first, pair.second, pair.kind, overlap, pair.wasted
+            )
+            .unwrap();
+        }
+        writeln!(text, "\n{} sections wasted\n\ncoverage:", self.wasted).unwrap();
+        let runs: Vec<String> = self
+            .coverage
+            .iter()
+            .map(|coverage| Sections::from(*coverage).to_string())
+            .collect();
+        let width = runs.iter().map(String::len).max().unwrap_or(0);
+        for (run, coverage) in runs.iter().zip(&self.coverage) {
+            writeln!(text, "{run:>width$} {:>4}", coverage.elves).unwrap();
+        }
+        text
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports are plain data")
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{parse_procedure, BlockMove, Crate, ProcedureError, Stacks};
+use aoc_common::ParseError;
+
+/// A crane, which differs from other models only in how many crates it can
+/// lift at once. Each lift keeps its crates in order, so lifting one at a
+/// time reverses a move and lifting them all keeps it as it was.
+pub trait Crane {
+    /// The most crates one lift can hold. Always at least 1.
+    fn capacity(&self) -> usize;
+
+    /// Moves `quantity` crates from the top of `source` to `destination`,
+    /// in as many lifts as it takes.
+    fn transfer(
+        &self,
+        stacks: &mut [Vec<Crate>],
+        source: usize,
+        destination: usize,
+        quantity: usize,
+    ) {
+        let capacity = self.capacity().max(1);
+        let mut remaining = quantity;
+        while remaining > 0 {
+            let lift = remaining.min(capacity);
+            let from = &mut stacks[source];
+            let crates = from.split_off(from.len() - lift);
+            stacks[destination].extend(crates);
+            remaining -= lift;
+        }
+    }
+}
+
+/// Moves crates one at a time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn capacity(&self) -> usize {
+        1
+    }
+}
+
+/// Moves any number of crates at once.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+}
+
+/// Moves up to this many crates at once. A capacity of 0 is treated as 1.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxLift(pub usize);
+
+impl Crane for MaxLift {
+    fn capacity(&self) -> usize {
+        self.0
+    }
+}
+
+/// The stacks as they were after one move.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// Which move this was, counting from 1.
+    pub number: usize,
+    pub block_move: BlockMove,
+    pub stacks: Stacks,
+}
+
+/// Works through a procedure one move at a time, checking each move against
+/// the stacks before making it. As an iterator it yields every step, and
+/// stops after the first move that cannot be made.
+pub struct Simulation<'a, C> {
+    input: &'a str,
+    crane: C,
+    stacks: Stacks,
+    moves: std::vec::IntoIter<(&'a str, BlockMove)>,
+    made: usize,
+    failed: bool,
+}
+
+impl<'a, C: Crane> Simulation<'a, C> {
+    pub fn new(input: &'a str, crane: C) -> Result<Self, ParseError<ProcedureError>> {
+        let procedure = parse_procedure(input)?;
+        Ok(Simulation {
+            input,
+            crane,
+            stacks: procedure.stacks,
+            moves: procedure.moves.into_iter(),
+            made: 0,
+            failed: false,
+        })
+    }
+
+    /// The stacks after the moves made so far.
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    /// Makes the next move, returning it, or `None` once there are no more.
+    pub fn step(&mut self) -> Result<Option<BlockMove>, ParseError<ProcedureError>> {
+        if self.failed {
+            return Ok(None);
+        }
+        let Some((line, block_move)) = self.moves.next() else {
+            return Ok(None);
+        };
+        if let Err(kind) = self.check(&block_move) {
+            self.failed = true;
+            return Err(ParseError::at(kind, self.input, line));
+        }
+        self.crane.transfer(
+            self.stacks.stacks_mut(),
+            block_move.source - 1,
+            block_move.destination - 1,
+            block_move.quantity,
+        );
+        self.made += 1;
+        Ok(Some(block_move))
+    }
+
+    /// Makes every remaining move, returning the final stacks.
+    pub fn run(mut self) -> Result<Stacks, ParseError<ProcedureError>> {
+        while self.step()?.is_some() {}
+        Ok(self.stacks)
+    }
+
+    fn check(&self, block_move: &BlockMove) -> Result<(), ProcedureError> {
+        let stacks = self.stacks.stacks();
+        let exists = |stack: usize| (1..=stacks.len()).contains(&stack);
+        if !exists(block_move.source) || !exists(block_move.destination) {
+            return Err(ProcedureError::UnknownStack);
+        }
+        let available = stacks[block_move.source - 1].len();
+        if available == 0 && block_move.quantity > 0 {
+            return Err(ProcedureError::EmptyStack);
+        }
+        if available < block_move.quantity {
+            return Err(ProcedureError::NotEnoughCrates);
+        }
+        Ok(())
+    }
+}
+
+impl<C: Crane> Iterator for Simulation<'_, C> {
+    type Item = Result<Step, ParseError<ProcedureError>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.step() {
+            Ok(Some(block_move)) => Some(Ok(Step {
+                number: self.made,
+                block_move,
+                stacks: self.stacks.clone(),
+            })),
+            Ok(None) => None,
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
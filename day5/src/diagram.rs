@@ -0,0 +1,235 @@
+use crate::ProcedureError;
+use aoc_common::ParseError;
+use std::ops::Range;
+
+/// A crate's name, the text between its brackets. Usually one letter.
+pub type Crate = String;
+
+/// Stacks of crates, bottom first, in the order of their labels. Stack `n`
+/// in a move is `stacks()[n - 1]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stacks {
+    stacks: Vec<Vec<Crate>>,
+}
+
+impl Stacks {
+    pub fn new(stacks: Vec<Vec<Crate>>) -> Self {
+        Stacks { stacks }
+    }
+
+    pub fn stacks(&self) -> &[Vec<Crate>] {
+        &self.stacks
+    }
+
+    pub fn stacks_mut(&mut self) -> &mut [Vec<Crate>] {
+        &mut self.stacks
+    }
+
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    /// The stack with the given 1-based label.
+    pub fn stack(&self, label: usize) -> Option<&[Crate]> {
+        self.stacks.get(label.checked_sub(1)?).map(Vec::as_slice)
+    }
+
+    /// The crate on top of each stack, run together. Empty stacks are skipped.
+    pub fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Draws the stacks the way the puzzle does, so single-letter crates in
+    /// fewer than ten stacks come out exactly as in the input. Wider names
+    /// and labels widen every column, which `parse_diagram` still reads.
+    pub fn render(&self) -> String {
+        let width = self
+            .stacks
+            .iter()
+            .flatten()
+            .map(|name| name.chars().count() + 2)
+            .chain(Some(self.stacks.len().to_string().len() + 2))
+            .max()
+            .unwrap_or(3)
+            .max(3);
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let row = |cells: Vec<String>| {
+            let cells: Vec<String> = cells.iter().map(|cell| format!("{cell:^width$}")).collect();
+            cells.join(" ") + "\n"
+        };
+        let mut diagram = String::new();
+        for level in (0..height).rev() {
+            diagram.push_str(&row(self
+                .stacks
+                .iter()
+                .map(|stack| {
+                    stack
+                        .get(level)
+                        .map_or(String::new(), |name| format!("[{name}]"))
+                })
+                .collect()));
+        }
+        diagram.push_str(&row((1..=self.stacks.len())
+            .map(|label| label.to_string())
+            .collect()));
+        diagram
+    }
+}
+
+/// Something in a diagram line, with the columns (in chars) it covers.
+struct Token<'a> {
+    columns: Range<usize>,
+    text: &'a str,
+}
+
+/// Splits the input at its first blank line, returning the diagram lines and
+/// everything after the blank line.
+pub(crate) fn split_sections(input: &str) -> Result<(Vec<&str>, &str), ParseError<ProcedureError>> {
+    let mut diagram = Vec::new();
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            let end = line.as_ptr() as usize - input.as_ptr() as usize + line.len();
+            return Ok((diagram, &input[end..]));
+        }
+        diagram.push(line);
+    }
+    Err(ParseError::at(
+        ProcedureError::MissingProcedure,
+        input,
+        &input[input.len()..],
+    ))
+}
+
+/// Reads the drawing of the stacks: rows of `[X]` crates above a row of
+/// stack labels `1 2 3...`. Lines may be ragged, labels may have several
+/// digits and crates may have names longer than one letter. Each crate
+/// belongs to the label its brackets sit over.
+pub fn parse_diagram(input: &str) -> Result<Stacks, ParseError<ProcedureError>> {
+    let (diagram, _) = split_sections(input)?;
+    parse_diagram_lines(input, &diagram)
+}
+
+pub(crate) fn parse_diagram_lines(
+    input: &str,
+    diagram: &[&str],
+) -> Result<Stacks, ParseError<ProcedureError>> {
+    let error = |kind, text| ParseError::at(kind, input, text);
+    let Some((label_line, crate_lines)) = diagram.split_last() else {
+        return Err(error(ProcedureError::MissingStackLabels, &input[..0]));
+    };
+
+    let labels = tokens(label_line, |text| text.chars().all(|c| c.is_ascii_digit()))
+        .map_err(|text| error(ProcedureError::MissingStackLabels, text))?;
+    if labels.is_empty() {
+        return Err(error(ProcedureError::MissingStackLabels, label_line));
+    }
+    for (index, label) in labels.iter().enumerate() {
+        if label.text.parse() != Ok(index + 1) {
+            return Err(error(ProcedureError::BadStackLabel, label.text));
+        }
+    }
+
+    // Read top down. Once a stack has a crate, every line below needs one
+    // there too, or the crate above the gap is floating.
+    let mut stacks: Vec<Vec<Crate>> = vec![Vec::new(); labels.len()];
+    for (row, line) in crate_lines.iter().enumerate() {
+        let crates = tokens(line, |text| text.starts_with('['))
+            .map_err(|text| error(ProcedureError::UnexpectedText, text))?;
+        let mut filled = vec![None; labels.len()];
+        for token in crates {
+            let name = crate_name(token.text).map_err(|kind| error(kind, token.text))?;
+            let stack = labels
+                .iter()
+                .position(|label| overlap(&label.columns, &token.columns))
+                .ok_or_else(|| error(ProcedureError::CrateWithoutStack, token.text))?;
+            if filled[stack].replace(token.text).is_some() {
+                return Err(error(ProcedureError::OverlappingCrates, token.text));
+            }
+            stacks[stack].push(name.to_string());
+        }
+        for (stack, crate_text) in filled.iter().enumerate() {
+            if crate_text.is_none() && !stacks[stack].is_empty() {
+                let above = crate_above(&crate_lines[..row], &labels[stack]);
+                return Err(error(ProcedureError::FloatingCrate, above.unwrap_or(line)));
+            }
+        }
+    }
+    for stack in &mut stacks {
+        stack.reverse();
+    }
+    Ok(Stacks { stacks })
+}
+
+/// Splits a line into whitespace-separated tokens, where `[...]` is always
+/// one token even if it holds spaces. Fails with the first token `valid`
+/// rejects.
+fn tokens<'a>(line: &'a str, valid: impl Fn(&str) -> bool) -> Result<Vec<Token<'a>>, &'a str> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().enumerate().peekable();
+    while let Some((column, (start, c))) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let mut end_column = column + 1;
+        let mut end = start + c.len_utf8();
+        while let Some(&(_, (offset, next))) = chars.peek() {
+            let ends_token = if c == '[' {
+                next == '['
+            } else {
+                next == '[' || next.is_whitespace()
+            };
+            if ends_token {
+                break;
+            }
+            chars.next();
+            end_column += 1;
+            end = offset + next.len_utf8();
+            if c == '[' && next == ']' {
+                break;
+            }
+        }
+        let text = line[start..end].trim_end();
+        if !valid(text) {
+            return Err(text);
+        }
+        tokens.push(Token {
+            columns: column..end_column,
+            text,
+        });
+    }
+    Ok(tokens)
+}
+
+fn crate_name(text: &str) -> Result<&str, ProcedureError> {
+    let name = text
+        .strip_prefix('[')
+        .and_then(|text| text.strip_suffix(']'))
+        .ok_or(ProcedureError::UnclosedCrate)?;
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(ProcedureError::BadCrateName);
+    }
+    Ok(name)
+}
+
+fn overlap(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// The lowest crate over `label` in `lines`.
+fn crate_above<'a>(lines: &[&'a str], label: &Token) -> Option<&'a str> {
+    lines.iter().rev().find_map(|above| {
+        tokens(above, |_| true)
+            .ok()?
+            .into_iter()
+            .find(|token| overlap(&label.columns, &token.columns))
+            .map(|token| token.text)
+    })
+}
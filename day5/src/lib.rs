@@ -0,0 +1,400 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use tracing::debug;
+
+mod crane;
+mod diagram;
+pub use crane::*;
+pub use diagram::*;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?)
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?)
+    }
+}
+
+pub fn part_1(input: &str) -> Result<String, ParseError<ProcedureError>> {
+    Ok(Simulation::new(input, CrateMover9000)?.run()?.tops())
+}
+
+pub fn part_2(input: &str) -> Result<String, ParseError<ProcedureError>> {
+    Ok(Simulation::new(input, CrateMover9001)?.run()?.tops())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcedureError {
+    MissingStackLabels,
+    BadStackLabel,
+    UnexpectedText,
+    UnclosedCrate,
+    BadCrateName,
+    CrateWithoutStack,
+    OverlappingCrates,
+    FloatingCrate,
+    MissingProcedure,
+    MissingKeyword(&'static str),
+    NotANumber,
+    UnknownStack,
+    EmptyStack,
+    NotEnoughCrates,
+}
+
+impl fmt::Display for ProcedureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcedureError::MissingStackLabels => {
+                write!(f, "expected a line of stack numbers under the crates")
+            }
+            ProcedureError::BadStackLabel => write!(f, "stacks should be numbered 1, 2, 3..."),
+            ProcedureError::UnexpectedText => write!(f, "expected a crate like [A]"),
+            ProcedureError::UnclosedCrate => write!(f, "crate is missing its closing ']'"),
+            ProcedureError::BadCrateName => {
+                write!(f, "crate names cannot be empty or contain spaces")
+            }
+            ProcedureError::CrateWithoutStack => write!(f, "crate is not above any stack number"),
+            ProcedureError::OverlappingCrates => {
+                write!(f, "crate is above the same stack as the one before it")
+            }
+            ProcedureError::FloatingCrate => write!(f, "crate has nothing underneath it"),
+            ProcedureError::MissingProcedure => {
+                write!(
+                    f,
+                    "expected a blank line and then the rearrangement procedure"
+                )
+            }
+            ProcedureError::MissingKeyword(keyword) => write!(f, "expected `{keyword}`"),
+            ProcedureError::NotANumber => write!(f, "expected a number"),
+            ProcedureError::UnknownStack => write!(f, "move refers to a stack that does not exist"),
+            ProcedureError::EmptyStack => write!(f, "move takes crates from an empty stack"),
+            ProcedureError::NotEnoughCrates => {
+                write!(f, "move takes more crates than the stack holds")
+            }
+        }
+    }
+}
+
+/// The starting stacks and every move, with the line each move is on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Procedure<'a> {
+    pub stacks: Stacks,
+    pub moves: Vec<(&'a str, BlockMove)>,
+}
+
+pub fn parse_procedure(input: &str) -> Result<Procedure<'_>, ParseError<ProcedureError>> {
+    let (diagram, procedure) = split_sections(input)?;
+    let stacks = parse_diagram_lines(input, &diagram)?;
+    debug!(stacks = stacks.len(), "read diagram");
+    let moves = procedure
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.parse()
+                .map(|block_move| (line, block_move))
+                .map_err(|error: ParseError<ProcedureError>| error.within(input, line))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Procedure { stacks, moves })
+}
+
+/// `move <quantity> from <source> to <destination>`, with stacks numbered
+/// from 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockMove {
+    pub source: usize,
+    pub destination: usize,
+    pub quantity: usize,
+}
+
+impl fmt::Display for BlockMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.quantity, self.source, self.destination
+        )
+    }
+}
+
+impl FromStr for BlockMove {
+    type Err = ParseError<ProcedureError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let missing = |keyword| ParseError::at(ProcedureError::MissingKeyword(keyword), s, s);
+        let (_, rest) = s.split_once("move").ok_or_else(|| missing("move"))?;
+        let (quantity, rest) = rest.split_once("from").ok_or_else(|| missing("from"))?;
+        let (source, destination) = rest.split_once("to").ok_or_else(|| missing("to"))?;
+        let number = |text: &str| {
+            let text = text.trim();
+            text.parse()
+                .map_err(|_| ParseError::at(ProcedureError::NotANumber, s, text))
+        };
+        Ok(Self {
+            source: number(source)?,
+            destination: number(destination)?,
+            quantity: number(quantity)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day05/dev.txt");
+
+        let sorted = part_1(input);
+
+        assert_eq!(sorted.unwrap(), "CMZ")
+    }
+
+    #[test]
+    fn part_2_given() {
+        let input = include_str!("../../inputs/day05/dev.txt");
+
+        let sorted = part_2(input);
+
+        assert_eq!(sorted.unwrap(), "MCD")
+    }
+
+    fn stacks(stacks: &[&[&str]]) -> Stacks {
+        Stacks::new(
+            stacks
+                .iter()
+                .map(|stack| stack.iter().map(|name| name.to_string()).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn parse_blocks_given() {
+        let input = include_str!("../../inputs/day05/dev.txt");
+        let expected_output = stacks(&[&["Z", "N"], &["M", "C", "D"], &["P"]]);
+
+        let sorted = parse_diagram(input);
+
+        assert_eq!(sorted.unwrap(), expected_output)
+    }
+
+    #[test]
+    fn crates_may_contain_digits() {
+        let input = "[1] [2]\n 1   2\n\nmove 1 from 1 to 2\n";
+
+        assert_eq!(part_1(input).unwrap(), "1");
+    }
+
+    #[test]
+    fn ragged_lines_and_long_labels() {
+        let input = "\
+[A]                                     [K]
+[B]                                 [J] [L]
+ 1   2   3   4   5   6   7   8   9   10  11
+
+move 2 from 11 to 2
+";
+        let expected = stacks(&[
+            &["B", "A"],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &["J"],
+            &["L", "K"],
+        ]);
+
+        assert_eq!(parse_diagram(input).unwrap(), expected);
+        assert_eq!(part_1(input).unwrap(), "ALJ");
+    }
+
+    #[test]
+    fn crates_with_long_names() {
+        let input = "[Fe]\n[Cu] [Au]\n  1    2\n\nmove 1 from 1 to 2\n";
+
+        assert_eq!(
+            parse_diagram(input).unwrap(),
+            stacks(&[&["Cu", "Fe"], &["Au"]])
+        );
+        assert_eq!(part_2(input).unwrap(), "CuFe");
+    }
+
+    #[test]
+    fn malformed_diagrams_are_located() {
+        let error = |input: &str| {
+            let error = parse_diagram(input).unwrap_err();
+            (error.kind, error.line, error.column, error.text)
+        };
+
+        assert_eq!(
+            error("[A] [B\n 1   2\n\n"),
+            (ProcedureError::UnclosedCrate, 1, 5, "[B".to_string())
+        );
+        assert_eq!(
+            error("[A] B\n 1   2\n\n"),
+            (ProcedureError::UnexpectedText, 1, 5, "B".to_string())
+        );
+        assert_eq!(
+            error("[A]\n    [B]\n 1\n\n"),
+            (ProcedureError::CrateWithoutStack, 2, 5, "[B]".to_string())
+        );
+        assert_eq!(
+            error("[A]\n    [B]\n 1   2\n\n"),
+            (ProcedureError::FloatingCrate, 1, 1, "[A]".to_string())
+        );
+        assert_eq!(
+            error("[A] [B]\n 1   3\n\n"),
+            (ProcedureError::BadStackLabel, 2, 6, "3".to_string())
+        );
+        assert_eq!(
+            error("[A] [B]\n\nmove 1 from 1 to 2"),
+            (ProcedureError::MissingStackLabels, 1, 1, "[A]".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_block_move() {
+        let input = "move 1 from 2 to 3";
+        let expected_output = BlockMove {
+            source: 2,
+            destination: 3,
+            quantity: 1,
+        };
+
+        let parsed: BlockMove = input.parse().unwrap();
+
+        assert_eq!(parsed, expected_output);
+    }
+
+    #[test]
+    fn bad_move_is_located() {
+        let input = "[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmove x from 2 to 1\n";
+
+        let error = part_1(input).unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(ProcedureError::NotANumber, 5, 6, "x")
+        );
+    }
+
+    #[test]
+    fn move_from_missing_stack_is_rejected() {
+        let input = "[A] [B]\n 1   2\n\nmove 1 from 3 to 2\n";
+
+        let error = part_2(input).unwrap_err();
+
+        assert_eq!(error.kind, ProcedureError::UnknownStack);
+        assert_eq!(error.line, 4);
+    }
+
+    #[test]
+    fn cranes_differ_only_in_capacity() {
+        let input = "[A]\n[B]\n[C]\n[D]\n[E]\n 1   2\n\nmove 5 from 1 to 2\n";
+        let moved = |crane: &dyn Fn(&str) -> Stacks| crane(input).stack(2).unwrap().join("");
+
+        assert_eq!(
+            moved(&|input| Simulation::new(input, CrateMover9000)
+                .unwrap()
+                .run()
+                .unwrap()),
+            "ABCDE"
+        );
+        assert_eq!(
+            moved(&|input| Simulation::new(input, CrateMover9001)
+                .unwrap()
+                .run()
+                .unwrap()),
+            "EDCBA"
+        );
+        assert_eq!(
+            moved(&|input| Simulation::new(input, MaxLift(2)).unwrap().run().unwrap()),
+            "BADCE"
+        );
+    }
+
+    #[test]
+    fn every_step_can_be_replayed() {
+        let input = include_str!("../../inputs/day05/dev.txt");
+
+        let steps: Vec<Step> = Simulation::new(input, CrateMover9000)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let tops: Vec<String> = steps.iter().map(|step| step.stacks.tops()).collect();
+        assert_eq!(tops, vec!["DCP", "CZ", "MZ", "CMZ"]);
+        assert_eq!(steps[1].number, 2);
+        assert_eq!(
+            steps[1].block_move,
+            BlockMove {
+                source: 1,
+                destination: 3,
+                quantity: 3
+            }
+        );
+    }
+
+    #[test]
+    fn replay_stops_at_a_bad_move() {
+        let input = "[A]\n 1   2\n\nmove 1 from 1 to 2\nmove 1 from 1 to 2\nmove 1 from 2 to 1\n";
+
+        let steps: Vec<_> = Simulation::new(input, CrateMover9001).unwrap().collect();
+
+        assert_eq!(steps.len(), 2);
+        assert_eq!(
+            steps[1],
+            Err(ParseError::new(
+                ProcedureError::EmptyStack,
+                5,
+                1,
+                "move 1 from 1 to 2"
+            ))
+        );
+    }
+
+    #[test]
+    fn diagrams_render_as_given() {
+        let input = include_str!("../../inputs/day05/dev.txt");
+        let (diagram, _) = input.split_once("\n\n").unwrap();
+
+        let rendered = parse_diagram(input).unwrap().render();
+
+        assert_eq!(rendered, format!("{diagram}\n"));
+    }
+
+    #[test]
+    fn rendered_steps_parse_back() {
+        let input = include_str!("../../inputs/day05/prod.txt");
+
+        for step in Simulation::new(input, CrateMover9001).unwrap().take(20) {
+            let step = step.unwrap();
+            let rendered = format!("{}\n{}\n", step.stacks.render(), step.block_move);
+            assert_eq!(parse_diagram(&rendered).unwrap(), step.stacks);
+        }
+    }
+
+    #[test]
+    fn wide_crates_widen_every_column() {
+        let stacks = stacks(&[&["Cu", "Fe"], &[], &["Au"]]);
+
+        assert_eq!(
+            stacks.render(),
+            "[Fe]          \n[Cu]      [Au]\n 1    2    3  \n"
+        );
+        assert_eq!(
+            parse_diagram(&format!("{}\n", stacks.render())).unwrap(),
+            stacks
+        );
+    }
+}
@@ -5,10 +5,21 @@ use day6::*;
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("map", |b| {
-        b.iter(|| find_unique_of_size_map(include_str!("../src/real"), 14).unwrap())
+        b.iter(|| find_unique_of_size_map(include_str!("../../inputs/day06/prod.txt"), 14).unwrap())
     });
     c.bench_function("non-map", |b| {
-        b.iter(|| find_unique_of_size(include_str!("../src/real"), 14).unwrap())
+        b.iter(|| find_unique_of_size(include_str!("../../inputs/day06/prod.txt"), 14).unwrap())
+    });
+
+    c.bench_function("last-seen", |b| {
+        b.iter(|| find_marker(include_bytes!("../../inputs/day06/prod.txt"), 14).unwrap())
+    });
+    c.bench_function("last-seen-stream", |b| {
+        b.iter(|| {
+            find_marker_in(&include_bytes!("../../inputs/day06/prod.txt")[..], 14)
+                .unwrap()
+                .unwrap()
+        })
     });
 
     c.bench_function("add-to-buffer", |b| {
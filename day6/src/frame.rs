@@ -0,0 +1,147 @@
+use crate::MarkerScanner;
+use std::iter::Peekable;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrameKind {
+    Packet,
+    Message,
+}
+
+/// How many distinct bytes in a row start each kind of frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MarkerLengths {
+    pub packet: usize,
+    pub message: usize,
+}
+
+impl Default for MarkerLengths {
+    /// The lengths the puzzle's device uses.
+    fn default() -> Self {
+        MarkerLengths {
+            packet: 4,
+            message: 14,
+        }
+    }
+}
+
+impl MarkerLengths {
+    pub fn of(&self, kind: FrameKind) -> usize {
+        match kind {
+            FrameKind::Packet => self.packet,
+            FrameKind::Message => self.message,
+        }
+    }
+}
+
+/// A marker and the data after it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Frame<'a> {
+    pub kind: FrameKind,
+    /// Where the marker starts in the stream.
+    pub offset: usize,
+    /// Everything from the end of the marker to the start of the next marker
+    /// of the same kind, or to the end of the stream.
+    pub payload: &'a [u8],
+}
+
+/// Every marker of one length in a stream, as the number of bytes read when
+/// it completes. The search starts over after each marker, so no two of
+/// them share a byte.
+#[derive(Clone, Debug)]
+pub struct Markers<'a> {
+    bytes: std::slice::Iter<'a, u8>,
+    scanner: MarkerScanner,
+}
+
+impl<'a> Markers<'a> {
+    /// Panics if `length` is 0.
+    pub fn new(input: &'a [u8], length: usize) -> Self {
+        Markers {
+            bytes: input.iter(),
+            scanner: MarkerScanner::new(length),
+        }
+    }
+}
+
+impl Iterator for Markers<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let end = self.bytes.find_map(|&byte| self.scanner.push(byte))?;
+        self.scanner.restart();
+        Some(end)
+    }
+}
+
+/// The frames of one kind, in order.
+#[derive(Clone, Debug)]
+struct Frames<'a> {
+    input: &'a [u8],
+    kind: FrameKind,
+    length: usize,
+    markers: Markers<'a>,
+    /// Where the next frame's marker ends.
+    next: Option<usize>,
+}
+
+impl<'a> Frames<'a> {
+    fn new(input: &'a [u8], kind: FrameKind, length: usize) -> Self {
+        let mut markers = Markers::new(input, length);
+        Frames {
+            input,
+            kind,
+            length,
+            next: markers.next(),
+            markers,
+        }
+    }
+}
+
+impl<'a> Iterator for Frames<'a> {
+    type Item = Frame<'a>;
+
+    fn next(&mut self) -> Option<Frame<'a>> {
+        let end = self.next?;
+        self.next = self.markers.next();
+        let payload_end = self
+            .next
+            .map_or(self.input.len(), |next| next - self.length);
+        Some(Frame {
+            kind: self.kind,
+            offset: end - self.length,
+            payload: &self.input[end..payload_end],
+        })
+    }
+}
+
+/// Splits a datastream into packets and messages. Each kind is framed on its
+/// own, so a message marker is usually also followed by packet markers
+/// within it. Frames come in order of where their markers start, packets
+/// first when both start together.
+#[derive(Clone, Debug)]
+pub struct Decoder<'a> {
+    packets: Peekable<Frames<'a>>,
+    messages: Peekable<Frames<'a>>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Panics if either length is 0.
+    pub fn new(input: &'a [u8], lengths: MarkerLengths) -> Self {
+        Decoder {
+            packets: Frames::new(input, FrameKind::Packet, lengths.packet).peekable(),
+            messages: Frames::new(input, FrameKind::Message, lengths.message).peekable(),
+        }
+    }
+}
+
+impl<'a> Iterator for Decoder<'a> {
+    type Item = Frame<'a>;
+
+    fn next(&mut self) -> Option<Frame<'a>> {
+        match (self.packets.peek(), self.messages.peek()) {
+            (Some(packet), Some(message)) if message.offset < packet.offset => self.messages.next(),
+            (Some(_), _) => self.packets.next(),
+            (None, _) => self.messages.next(),
+        }
+    }
+}
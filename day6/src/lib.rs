@@ -1,22 +1,51 @@
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::{HashMap, HashSet, VecDeque};
 
+mod frame;
+mod marker;
+pub use frame::*;
+pub use marker::*;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        solve(input, 4)
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        solve(input, 14)
+    }
+}
+
+fn solve(input: &str, length: usize) -> Result<String> {
+    find_marker(input.as_bytes(), length)
+        .map(|marker| marker.to_string())
+        .ok_or(MarkerError::NotFound { length }.into())
+}
+
 pub fn find_unique_of_size(input: &str, length: usize) -> Result<usize> {
     let max = input.len();
     let mut buffer = VecDeque::new();
     let mut input_chars: VecDeque<char> = input.chars().collect();
     for _ in 0..length - 1 {
-        buffer.push_back(input_chars.pop_front().unwrap());
+        let Some(next_char) = input_chars.pop_front() else {
+            break;
+        };
+        buffer.push_back(next_char);
     }
     for index in length - 1..max {
-        buffer.push_back(input_chars.pop_front().unwrap());
+        let Some(next_char) = input_chars.pop_front() else {
+            break;
+        };
+        buffer.push_back(next_char);
         let unique = fully_unique(&buffer);
         if unique {
             return Ok(index + 1);
         }
         buffer.pop_front();
     }
-    unreachable!("Could not find a unique set");
+    Err(MarkerError::NotFound { length }.into())
 }
 
 pub fn find_unique_of_size_map(input: &str, length: usize) -> Result<usize> {
@@ -24,11 +53,15 @@ pub fn find_unique_of_size_map(input: &str, length: usize) -> Result<usize> {
     let mut buffer = SmartBuffer::new();
     let mut input_chars: VecDeque<char> = input.chars().collect();
     for _ in 0..length - 1 {
-        let new_char = input_chars.pop_front().unwrap();
+        let Some(new_char) = input_chars.pop_front() else {
+            break;
+        };
         buffer.add(new_char);
     }
     for index in length - 1..max {
-        let new_char = input_chars.pop_front().unwrap();
+        let Some(new_char) = input_chars.pop_front() else {
+            break;
+        };
         buffer.add(new_char);
         //println!("{:?}", buffer);
 
@@ -39,7 +72,7 @@ pub fn find_unique_of_size_map(input: &str, length: usize) -> Result<usize> {
 
         buffer.pop();
     }
-    unreachable!("Could not find a unique set");
+    Err(MarkerError::NotFound { length }.into())
 }
 
 #[derive(Debug)]
@@ -50,6 +83,12 @@ pub struct SmartBuffer {
     hot_map_threshold: usize,
 }
 
+impl Default for SmartBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SmartBuffer {
     pub fn new() -> Self {
         Self {
@@ -59,7 +98,7 @@ impl SmartBuffer {
             hot_map_threshold: 2,
         }
     }
-    pub fn add(self: &mut Self, new_char: char) {
+    pub fn add(&mut self, new_char: char) {
         let current_count: usize = match self.main_map.get(&new_char) {
             Some(num) => *num,
             _ => 0,
@@ -71,7 +110,7 @@ impl SmartBuffer {
         self.main_map.insert(new_char, new_count);
         self.vec.push_back(new_char);
     }
-    pub fn pop(self: &mut Self) {
+    pub fn pop(&mut self) {
         let old_char = self.vec.pop_front().unwrap();
         let current_count: usize = match self.main_map.get(&old_char) {
             Some(num) => *num,
@@ -89,7 +128,7 @@ impl SmartBuffer {
     }
     pub fn only_has_unique(&self) -> bool {
         let hot_empty = self.hot_map.is_empty();
-        return hot_empty && self.main_map.iter().all(|(_, &value)| value == 1);
+        hot_empty && self.main_map.iter().all(|(_, &value)| value == 1)
     }
 }
 
@@ -104,7 +143,7 @@ mod tests {
 
     #[test]
     fn part_1_no_map() {
-        let input = include_str!("./given");
+        let input = include_str!("../../inputs/day06/dev.txt");
 
         let output = find_unique_of_size(input, 4).unwrap();
 
@@ -113,7 +152,7 @@ mod tests {
 
     #[test]
     fn part_1_map() {
-        let input = include_str!("./given");
+        let input = include_str!("../../inputs/day06/dev.txt");
 
         let output = find_unique_of_size_map(input, 4).unwrap();
 
@@ -122,7 +161,7 @@ mod tests {
 
     #[test]
     fn part_2_map() {
-        let input = include_str!("./real");
+        let input = include_str!("../../inputs/day06/prod.txt");
 
         let output = find_unique_of_size_map(input, 14).unwrap();
 
@@ -131,10 +170,151 @@ mod tests {
 
     #[test]
     fn part_2_no_map() {
-        let input = include_str!("./real");
+        let input = include_str!("../../inputs/day06/prod.txt");
 
         let output = find_unique_of_size(input, 14).unwrap();
 
         assert_eq!(output, 3298);
     }
+
+    #[test]
+    fn markers_given() {
+        let examples = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (input, packet, message) in examples {
+            assert_eq!(find_marker(input.as_bytes(), 4), Some(packet), "{input}");
+            assert_eq!(find_marker(input.as_bytes(), 14), Some(message), "{input}");
+        }
+        assert_eq!(find_marker(b"abcabc", 4), None);
+        assert_eq!(find_marker(b"a", 1), Some(1));
+    }
+
+    #[test]
+    fn markers_in_a_stream() {
+        let input = include_str!("../../inputs/day06/prod.txt");
+
+        // A reader that hands over a few bytes at a time, so markers land
+        // across reads.
+        struct Trickle<'a>(&'a [u8]);
+        impl std::io::Read for Trickle<'_> {
+            fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+                let read = self.0.len().min(buffer.len()).min(3);
+                buffer[..read].copy_from_slice(&self.0[..read]);
+                self.0 = &self.0[read..];
+                Ok(read)
+            }
+        }
+
+        assert_eq!(
+            find_marker_in(Trickle(input.as_bytes()), 14).unwrap(),
+            Some(3298)
+        );
+        assert_eq!(find_marker_in(input.as_bytes(), 14).unwrap(), Some(3298));
+        assert_eq!(find_marker_in(&b"aaaa"[..], 2).unwrap(), None);
+    }
+
+    #[test]
+    fn missing_markers_are_errors() {
+        for input in ["abcabcabc", "ab", ""] {
+            for find in [find_unique_of_size, find_unique_of_size_map] {
+                let error = find(input, 4).unwrap_err();
+                assert_eq!(
+                    error.downcast_ref(),
+                    Some(&MarkerError::NotFound { length: 4 }),
+                    "{input}"
+                );
+            }
+        }
+        assert!(Day6.part_2("abcdefghijklm").is_err());
+    }
+
+    #[test]
+    fn every_marker_is_found() {
+        let markers: Vec<usize> = Markers::new(b"abcdaabcd", 4).collect();
+
+        assert_eq!(markers, [4, 9]);
+        assert_eq!(Markers::new(b"aaaa", 2).count(), 0);
+    }
+
+    #[test]
+    fn streams_split_into_frames() {
+        let input = b"abcddbcdefghijklmnoppqqrstu";
+        let lengths = MarkerLengths {
+            packet: 3,
+            message: 14,
+        };
+
+        let frames: Vec<Frame> = Decoder::new(input, lengths).collect();
+
+        assert_eq!(
+            frames,
+            [
+                Frame {
+                    kind: FrameKind::Packet,
+                    offset: 0,
+                    payload: b"d",
+                },
+                Frame {
+                    kind: FrameKind::Packet,
+                    offset: 4,
+                    payload: b"",
+                },
+                Frame {
+                    kind: FrameKind::Message,
+                    offset: 5,
+                    payload: b"ppqqrstu",
+                },
+                Frame {
+                    kind: FrameKind::Packet,
+                    offset: 7,
+                    payload: b"",
+                },
+                Frame {
+                    kind: FrameKind::Packet,
+                    offset: 10,
+                    payload: b"",
+                },
+                Frame {
+                    kind: FrameKind::Packet,
+                    offset: 13,
+                    payload: b"",
+                },
+                Frame {
+                    kind: FrameKind::Packet,
+                    offset: 16,
+                    payload: b"ppq",
+                },
+                Frame {
+                    kind: FrameKind::Packet,
+                    offset: 22,
+                    payload: b"tu",
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn first_frames_match_the_answers() {
+        let input = include_str!("../../inputs/day06/prod.txt");
+
+        let mut decoder = Decoder::new(input.as_bytes(), MarkerLengths::default());
+        let packet = decoder
+            .find(|frame| frame.kind == FrameKind::Packet)
+            .unwrap();
+        let message = Decoder::new(input.as_bytes(), MarkerLengths::default())
+            .find(|frame| frame.kind == FrameKind::Message)
+            .unwrap();
+
+        assert_eq!(
+            packet.offset + 4,
+            Day6.part_1(input).unwrap().parse().unwrap()
+        );
+        assert_eq!(message.offset + 14, 3298);
+    }
 }
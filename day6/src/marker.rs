@@ -0,0 +1,90 @@
+use std::fmt;
+use std::io::{self, Read};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkerError {
+    /// The stream never has this many distinct bytes in a row.
+    NotFound { length: usize },
+}
+
+impl fmt::Display for MarkerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkerError::NotFound { length } => {
+                write!(f, "no {length} distinct characters in a row")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MarkerError {}
+
+/// Finds a marker one byte at a time, remembering where each byte value was
+/// last seen. The window of distinct bytes only ever moves forward, so the
+/// whole search is a single pass with no allocation.
+#[derive(Clone, Debug)]
+pub struct MarkerScanner {
+    length: usize,
+    /// One past the position each byte was last seen at, or 0 if never.
+    last_seen: [usize; 256],
+    /// Where the current run of distinct bytes starts.
+    start: usize,
+    /// How many bytes have been pushed.
+    position: usize,
+}
+
+impl MarkerScanner {
+    /// Panics if `length` is 0.
+    pub fn new(length: usize) -> Self {
+        assert!(length > 0, "markers are at least one byte long");
+        MarkerScanner {
+            length,
+            last_seen: [0; 256],
+            start: 0,
+            position: 0,
+        }
+    }
+
+    /// Adds the next byte, returning how many bytes have been read if it
+    /// completes a marker.
+    pub fn push(&mut self, byte: u8) -> Option<usize> {
+        let seen = &mut self.last_seen[usize::from(byte)];
+        self.start = self.start.max(*seen);
+        self.position += 1;
+        *seen = self.position;
+        (self.position - self.start >= self.length).then_some(self.position)
+    }
+
+    /// Forgets the bytes pushed so far, so the next marker cannot overlap
+    /// any of them.
+    pub fn restart(&mut self) {
+        self.start = self.position;
+    }
+}
+
+/// How many bytes have to be read before the first `length` distinct ones
+/// in a row, or `None` if there are never that many.
+///
+/// Panics if `length` is 0.
+pub fn find_marker(input: &[u8], length: usize) -> Option<usize> {
+    let mut scanner = MarkerScanner::new(length);
+    input.iter().find_map(|&byte| scanner.push(byte))
+}
+
+/// Like [`find_marker`], but reads from a stream, stopping as soon as the
+/// marker has arrived.
+pub fn find_marker_in(mut reader: impl Read, length: usize) -> io::Result<Option<usize>> {
+    let mut scanner = MarkerScanner::new(length);
+    let mut buffer = [0; 4096];
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(None),
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        if let Some(marker) = buffer[..read].iter().find_map(|&byte| scanner.push(byte)) {
+            return Ok(Some(marker));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::File;
+
+/// Where a directory lives in its [`FileSystem`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DirId(usize);
+
+impl DirId {
+    /// Where the directory comes in [`FileSystem::directories`] and
+    /// [`FileSystem::sizes`].
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+pub struct Directory {
+    pub name: String,
+    pub parent: Option<DirId>,
+    pub directories: Vec<DirId>,
+    pub files: Vec<File>,
+}
+
+impl Directory {
+    pub fn new(name: String) -> Self {
+        Directory {
+            name,
+            parent: None,
+            directories: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+}
+
+/// A directory tree kept in one arena. Directories point at their parents
+/// and children by index, so moving up and down the tree needs no shared
+/// ownership, and every directory comes after its parent.
+pub struct FileSystem {
+    directories: Vec<Directory>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: DirId = DirId(0);
+
+    /// A file system holding only an empty `/`.
+    pub fn new() -> Self {
+        FileSystem {
+            directories: vec![Directory::new("/".to_string())],
+        }
+    }
+
+    pub fn directory(&self, id: DirId) -> &Directory {
+        &self.directories[id.0]
+    }
+
+    /// Every directory, with the root first.
+    pub fn directories(&self) -> impl Iterator<Item = (DirId, &Directory)> {
+        self.directories
+            .iter()
+            .enumerate()
+            .map(|(index, directory)| (DirId(index), directory))
+    }
+
+    pub fn parent(&self, id: DirId) -> Option<DirId> {
+        self.directory(id).parent
+    }
+
+    /// The absolute path of a directory, such as `/a/e`.
+    pub fn path(&self, id: DirId) -> String {
+        let mut names = Vec::new();
+        let mut directory = id;
+        while let Some(parent) = self.parent(directory) {
+            names.push(self.directory(directory).name.as_str());
+            directory = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    pub fn child(&self, id: DirId, name: &str) -> Option<DirId> {
+        self.directory(id)
+            .directories
+            .iter()
+            .copied()
+            .find(|&child| self.directory(child).name == name)
+    }
+
+    /// Adds `directory` inside `parent`. If `parent` already has a directory
+    /// of that name, that one is returned instead.
+    pub fn add_directory(&mut self, parent: DirId, mut directory: Directory) -> DirId {
+        if let Some(existing) = self.child(parent, &directory.name) {
+            return existing;
+        }
+        let id = DirId(self.directories.len());
+        directory.parent = Some(parent);
+        self.directories.push(directory);
+        self.directories[parent.0].directories.push(id);
+        id
+    }
+
+    pub fn add_file(&mut self, directory: DirId, file: File) {
+        self.directories[directory.0].files.push(file);
+    }
+
+    /// The total size of everything in a directory, however deeply nested.
+    pub fn size(&self, id: DirId) -> u64 {
+        let directory = self.directory(id);
+        let files: u64 = directory
+            .files
+            .iter()
+            .map(|file| u64::from(file.size))
+            .sum();
+        files
+            + directory
+                .directories
+                .iter()
+                .map(|&child| self.size(child))
+                .sum::<u64>()
+    }
+
+    /// The total size of every directory, indexed like [`directories`]. Each
+    /// child comes after its parent, so adding them up from the back totals
+    /// the whole tree in one pass.
+    ///
+    /// [`directories`]: FileSystem::directories
+    pub fn sizes(&self) -> Vec<u64> {
+        let mut sizes: Vec<u64> = self
+            .directories
+            .iter()
+            .map(|directory| {
+                directory
+                    .files
+                    .iter()
+                    .map(|file| u64::from(file.size))
+                    .sum()
+            })
+            .collect();
+        for (index, directory) in self.directories.iter().enumerate().rev() {
+            if let Some(parent) = directory.parent {
+                sizes[parent.0] += sizes[index];
+            }
+        }
+        sizes
+    }
+}
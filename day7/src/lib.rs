@@ -0,0 +1,328 @@
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+mod filesystem;
+mod transcript;
+mod usage;
+pub use filesystem::*;
+pub use transcript::*;
+pub use usage::*;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+const DISK_SIZE: u64 = 70_000_000;
+const SPACE_NEEDED: u64 = 30_000_000;
+
+pub struct File {
+    pub size: u32,
+    pub name: String,
+}
+
+impl FromStr for File {
+    type Err = ParseError<TerminalError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (size, name) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(TerminalError::MissingName, s, s))?;
+        Ok(Self {
+            size: size
+                .parse()
+                .map_err(|_| ParseError::at(TerminalError::NotASize, s, size))?,
+            name: name.trim().to_string(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerminalError {
+    UnknownCommand,
+    MissingCdTarget,
+    UnknownDirectory,
+    AboveRoot,
+    NotADirectory,
+    MissingName,
+    NotASize,
+    InconsistentSize,
+    WrongDirectory,
+    UnexpectedOutput,
+}
+
+impl fmt::Display for TerminalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TerminalError::UnknownCommand => write!(f, "expected `cd`, `ls` or `pwd`"),
+            TerminalError::MissingCdTarget => write!(f, "`cd` needs a directory"),
+            TerminalError::UnknownDirectory => {
+                write!(f, "no directory with this name has been listed here")
+            }
+            TerminalError::AboveRoot => write!(f, "cannot leave the root directory"),
+            TerminalError::NotADirectory => write!(f, "expected `dir`"),
+            TerminalError::MissingName => write!(f, "expected a size and a name"),
+            TerminalError::NotASize => write!(f, "file size is not a number"),
+            TerminalError::InconsistentSize => {
+                write!(f, "this file was listed before with a different size")
+            }
+            TerminalError::WrongDirectory => write!(f, "this is not the current directory"),
+            TerminalError::UnexpectedOutput => write!(f, "expected a command"),
+        }
+    }
+}
+
+impl FromStr for Directory {
+    type Err = ParseError<TerminalError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            Some(("dir", name)) => Ok(Directory::new(name.to_string())),
+            _ => Err(ParseError::at(TerminalError::NotADirectory, s, s)),
+        }
+    }
+}
+
+/// The total size of every directory holding at most 100000.
+pub fn part_1(input: &str) -> Result<u64, ParseError<TerminalError>> {
+    let sizes = build_tree(input)?.sizes();
+    Ok(sizes.into_iter().filter(|&size| size <= 100_000).sum())
+}
+
+/// The size of the smallest directory that frees enough space to update.
+pub fn part_2(input: &str) -> Result<u64, ParseError<TerminalError>> {
+    let sizes = build_tree(input)?.sizes();
+    let used = sizes[0];
+    let to_free = (used + SPACE_NEEDED).saturating_sub(DISK_SIZE);
+    if to_free == 0 {
+        return Ok(0);
+    }
+    Ok(sizes
+        .into_iter()
+        .filter(|&size| size >= to_free)
+        .min()
+        .expect("the root frees enough space"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day07/dev.txt");
+
+        let result = part_1(input).unwrap();
+
+        assert_eq!(result, 95437);
+    }
+
+    #[test]
+    fn part_2_given() {
+        let input = include_str!("../../inputs/day07/dev.txt");
+
+        let result = part_2(input).unwrap();
+
+        assert_eq!(result, 24933642);
+    }
+
+    #[test]
+    fn build_tree_given() {
+        let input = include_str!("../../inputs/day07/dev.txt");
+
+        let tree = build_tree(input).ok().unwrap();
+        let root = tree.directory(FileSystem::ROOT);
+        let e = tree
+            .child(tree.child(FileSystem::ROOT, "a").unwrap(), "e")
+            .unwrap();
+
+        assert_eq!(root.directories.len(), 2);
+        assert_eq!(root.files.len(), 2);
+        assert_eq!(tree.size(e), 584);
+        assert_eq!(tree.size(FileSystem::ROOT), 48381165);
+        assert_eq!(
+            tree.directories()
+                .map(|(id, _)| tree.size(id))
+                .collect::<Vec<_>>(),
+            tree.sizes()
+        );
+    }
+
+    #[test]
+    fn cd_up_follows_parents() {
+        let input = "$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\ndir b\n$ cd b\n$ ls\n5 x\n$ cd ..\n$ cd ..\n$ cd ..\n";
+
+        let error = build_tree(input).err().unwrap();
+
+        assert_eq!(
+            error,
+            ParseError::new(TerminalError::AboveRoot, 12, 6, "..")
+        );
+    }
+
+    #[test]
+    fn bad_file_size_is_located() {
+        let input = "$ cd /\n$ ls\ndir a\n12x b.txt\n";
+
+        let error = build_tree(input).err().unwrap();
+
+        assert_eq!(error, ParseError::new(TerminalError::NotASize, 4, 1, "12x"));
+    }
+
+    #[test]
+    fn transcripts_with_paths_pwd_and_relisting() {
+        let input = "$ cd /\n$ ls\ndir a\n10 top file.txt\n$ cd a\n$ ls\ndir b c\n20 x\n\
+                     $ cd b c/../b c/.\n$ pwd\n/a/b c\n$ ls\n30 y\n$ cd /\n$ ls\ndir a\n\
+                     10 top file.txt\n$ cd /a/b c\n$ ls\n30 y\n";
+
+        let tree = build_tree(input).unwrap();
+        let a = tree.child(FileSystem::ROOT, "a").unwrap();
+        let b = tree.child(a, "b c").unwrap();
+
+        assert_eq!(
+            tree.directory(FileSystem::ROOT).files[0].name,
+            "top file.txt"
+        );
+        assert_eq!(tree.path(b), "/a/b c");
+        assert_eq!(tree.sizes(), [60, 50, 30]);
+    }
+
+    #[test]
+    fn inconsistent_transcripts_are_located() {
+        let cases = [
+            (
+                "$ ls\n5 a\n$ ls\n6 a\n",
+                TerminalError::InconsistentSize,
+                4,
+                1,
+                "6",
+            ),
+            (
+                "$ ls\ndir a\n$ cd a\n$ pwd\n/b\n",
+                TerminalError::WrongDirectory,
+                5,
+                1,
+                "/b",
+            ),
+            (
+                "$ cd /\n5 a\n",
+                TerminalError::UnexpectedOutput,
+                2,
+                1,
+                "5 a",
+            ),
+            (
+                "$ ls\ndir a\n$ cd a/b\n",
+                TerminalError::UnknownDirectory,
+                3,
+                8,
+                "b",
+            ),
+            ("$ cd\n", TerminalError::MissingCdTarget, 1, 3, "cd"),
+            (
+                "$ rm -rf /\n",
+                TerminalError::UnknownCommand,
+                1,
+                3,
+                "rm -rf /",
+            ),
+        ];
+
+        for (input, kind, line, column, text) in cases {
+            assert_eq!(
+                build_tree(input).err(),
+                Some(ParseError::new(kind, line, column, text)),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn tree_and_du_given() {
+        let input = include_str!("../../inputs/day07/dev.txt");
+
+        let tree = build_tree(input).unwrap();
+
+        assert_eq!(
+            tree.to_tree(),
+            "/ (48381165)
+├── a/ (94853)
+│   ├── e/ (584)
+│   │   └── i (584)
+│   ├── f (29116)
+│   ├── g (2557)
+│   └── h.lst (62596)
+├── b.txt (14848514)
+├── c.dat (8504156)
+└── d/ (24933642)
+    ├── d.ext (5626152)
+    ├── d.log (8033020)
+    ├── j (4060174)
+    └── k (7214296)
+"
+        );
+        let du = tree.to_du();
+        let du: Vec<&str> = du.lines().take(3).collect();
+        assert_eq!(du, ["48381165\t/", "24933642\t/d", "14848514\t/b.txt"]);
+        assert_eq!(tree.entries().len(), 14);
+    }
+
+    #[test]
+    fn find_given() {
+        let input = include_str!("../../inputs/day07/dev.txt");
+        let tree = build_tree(input).unwrap();
+        let paths = |query: Query| -> Vec<String> {
+            tree.find(&query)
+                .into_iter()
+                .map(|entry| entry.path)
+                .collect()
+        };
+
+        assert_eq!(
+            paths(Query {
+                name: Some("d*".to_string()),
+                ..Query::default()
+            }),
+            ["/d", "/d/d.ext", "/d/d.log"]
+        );
+        assert_eq!(
+            paths(Query {
+                min_size: Some(2557),
+                max_size: Some(29116),
+                ..Query::default()
+            }),
+            ["/a/f", "/a/g"]
+        );
+        assert_eq!(
+            paths(Query {
+                name: Some("?.*".to_string()),
+                ..Query::default()
+            }),
+            ["/a/h.lst", "/b.txt", "/c.dat", "/d/d.ext", "/d/d.log"]
+        );
+    }
+
+    #[test]
+    fn json_given() {
+        let input = include_str!("../../inputs/day07/dev.txt");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&build_tree(input).unwrap().to_json()).unwrap();
+
+        assert_eq!(json["size"], 48381165);
+        assert_eq!(json["children"][0]["name"], "a");
+        assert_eq!(
+            json["children"][0]["children"][0]["children"][0]["kind"],
+            "file"
+        );
+        assert!(json["children"][1].get("children").is_none());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{DirId, Directory, File, FileSystem, TerminalError};
+use aoc_common::ParseError;
+
+/// What the lines after a command are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Output {
+    /// Nothing is expected, as after `cd`.
+    Nothing,
+    /// The contents of the current directory.
+    Listing,
+    /// The current directory's path.
+    WorkingDirectory,
+}
+
+/// Replays a terminal transcript line by line, building up the file system
+/// it explores and checking its output agrees with what came before.
+pub struct Shell<'a> {
+    input: &'a str,
+    tree: FileSystem,
+    cwd: DirId,
+    output: Output,
+}
+
+impl<'a> Shell<'a> {
+    /// `input` is the whole transcript. Every line given to
+    /// [`line`](Shell::line) must come from it, so errors can be located.
+    pub fn new(input: &'a str) -> Self {
+        Shell {
+            input,
+            tree: FileSystem::new(),
+            cwd: FileSystem::ROOT,
+            output: Output::Nothing,
+        }
+    }
+
+    pub fn tree(&self) -> &FileSystem {
+        &self.tree
+    }
+
+    pub fn into_tree(self) -> FileSystem {
+        self.tree
+    }
+
+    pub fn cwd(&self) -> DirId {
+        self.cwd
+    }
+
+    /// The path of the current directory, as `pwd` would print it.
+    pub fn pwd(&self) -> String {
+        self.tree.path(self.cwd)
+    }
+
+    pub fn line(&mut self, line: &'a str) -> Result<(), ParseError<TerminalError>> {
+        if let Some(command) = line.strip_prefix("$ ") {
+            return self.command(command);
+        }
+        match self.output {
+            Output::Listing => self.listed(line),
+            Output::WorkingDirectory if line == self.pwd() => Ok(()),
+            Output::WorkingDirectory => Err(self.error(TerminalError::WrongDirectory, line)),
+            Output::Nothing => Err(self.error(TerminalError::UnexpectedOutput, line)),
+        }
+    }
+
+    fn command(&mut self, command: &'a str) -> Result<(), ParseError<TerminalError>> {
+        let (name, argument) = command.split_once(' ').unwrap_or((command, ""));
+        self.output = match (name, argument.trim()) {
+            ("ls", "") => Output::Listing,
+            ("pwd", "") => Output::WorkingDirectory,
+            ("cd", "") => return Err(self.error(TerminalError::MissingCdTarget, command)),
+            ("cd", path) => {
+                self.cwd = self.resolve(path)?;
+                Output::Nothing
+            }
+            _ => return Err(self.error(TerminalError::UnknownCommand, command)),
+        };
+        Ok(())
+    }
+
+    /// Follows a path such as `/a/b` or `a/../c` from the current directory.
+    /// Every directory on the way must have been listed already.
+    fn resolve(&self, path: &'a str) -> Result<DirId, ParseError<TerminalError>> {
+        let mut directory = if path.starts_with('/') {
+            FileSystem::ROOT
+        } else {
+            self.cwd
+        };
+        for segment in path.split('/') {
+            directory = match segment {
+                "" | "." => directory,
+                ".." => self
+                    .tree
+                    .parent(directory)
+                    .ok_or_else(|| self.error(TerminalError::AboveRoot, segment))?,
+                name => self
+                    .tree
+                    .child(directory, name)
+                    .ok_or_else(|| self.error(TerminalError::UnknownDirectory, segment))?,
+            };
+        }
+        Ok(directory)
+    }
+
+    /// Records one line of `ls` output. Listing a directory again adds only
+    /// what is new, and a file whose size has changed is an error.
+    fn listed(&mut self, line: &'a str) -> Result<(), ParseError<TerminalError>> {
+        if line.starts_with("dir ") {
+            let dir = line
+                .parse::<Directory>()
+                .map_err(|err| err.within(self.input, line))?;
+            self.tree.add_directory(self.cwd, dir);
+            return Ok(());
+        }
+        let file = line
+            .parse::<File>()
+            .map_err(|err| err.within(self.input, line))?;
+        let files = &self.tree.directory(self.cwd).files;
+        match files.iter().find(|listed| listed.name == file.name) {
+            None => self.tree.add_file(self.cwd, file),
+            Some(listed) if listed.size == file.size => {}
+            Some(_) => {
+                let size = line.split(' ').next().unwrap_or(line);
+                return Err(self.error(TerminalError::InconsistentSize, size));
+            }
+        }
+        Ok(())
+    }
+
+    fn error(&self, kind: TerminalError, text: &str) -> ParseError<TerminalError> {
+        ParseError::at(kind, self.input, text)
+    }
+}
+
+/// Replays a whole transcript. Blank lines are skipped.
+pub fn build_tree(input: &str) -> Result<FileSystem, ParseError<TerminalError>> {
+    let mut shell = Shell::new(input);
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        shell.line(line)?;
+    }
+    Ok(shell.into_tree())
+}
@@ -0,0 +1,218 @@
+use crate::{DirId, FileSystem};
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    Directory,
+    File,
+}
+
+/// A file or directory with its full path and total size.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Entry {
+    pub path: String,
+    pub kind: EntryKind,
+    pub size: u64,
+}
+
+/// One directory or file in a JSON export, with everything inside it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Node {
+    pub name: String,
+    pub kind: EntryKind,
+    pub size: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Node>,
+}
+
+/// Which entries a [`FileSystem::find`] returns. Every condition given must
+/// hold.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Query {
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    /// A pattern for the entry's own name, where `*` matches any run of
+    /// characters and `?` any one character.
+    pub name: Option<String>,
+}
+
+impl Query {
+    pub fn matches(&self, name: &str, size: u64) -> bool {
+        self.min_size.is_none_or(|min| size >= min)
+            && self.max_size.is_none_or(|max| size <= max)
+            && self
+                .name
+                .as_deref()
+                .is_none_or(|pattern| glob(pattern, name))
+    }
+}
+
+/// Matches `*` and `?` wildcards, backtracking only to the last `*`.
+fn glob(pattern: &str, name: &str) -> bool {
+    let (pattern, name): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), name.chars().collect());
+    let (mut p, mut n) = (0, 0);
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// A directory's subdirectories and files by name, for listings.
+enum Child {
+    Directory(DirId),
+    File(u64),
+}
+
+impl FileSystem {
+    fn children(&self, id: DirId) -> Vec<(&str, Child)> {
+        let directory = self.directory(id);
+        let mut children: Vec<(&str, Child)> = directory
+            .directories
+            .iter()
+            .map(|&child| (self.directory(child).name.as_str(), Child::Directory(child)))
+            .chain(
+                directory
+                    .files
+                    .iter()
+                    .map(|file| (file.name.as_str(), Child::File(u64::from(file.size)))),
+            )
+            .collect();
+        children.sort_by_key(|&(name, _)| name);
+        children
+    }
+
+    /// Every directory and file under `/`, in the order `tree` lists them.
+    pub fn entries(&self) -> Vec<Entry> {
+        let sizes = self.sizes();
+        let mut entries = vec![Entry {
+            path: "/".to_string(),
+            kind: EntryKind::Directory,
+            size: sizes[FileSystem::ROOT.index()],
+        }];
+        self.push_entries(&mut entries, FileSystem::ROOT, "", &sizes);
+        entries
+    }
+
+    fn push_entries(&self, entries: &mut Vec<Entry>, id: DirId, path: &str, sizes: &[u64]) {
+        for (name, child) in self.children(id) {
+            let path = format!("{path}/{name}");
+            match child {
+                Child::Directory(child) => {
+                    entries.push(Entry {
+                        path: path.clone(),
+                        kind: EntryKind::Directory,
+                        size: sizes[child.index()],
+                    });
+                    self.push_entries(entries, child, &path, sizes);
+                }
+                Child::File(size) => entries.push(Entry {
+                    path,
+                    kind: EntryKind::File,
+                    size,
+                }),
+            }
+        }
+    }
+
+    /// An indented listing like `tree` prints, with each entry's size.
+    pub fn to_tree(&self) -> String {
+        let sizes = self.sizes();
+        let mut text = format!("/ ({})\n", sizes[FileSystem::ROOT.index()]);
+        self.write_tree(&mut text, FileSystem::ROOT, "", &sizes);
+        text
+    }
+
+    fn write_tree(&self, text: &mut String, id: DirId, indent: &str, sizes: &[u64]) {
+        let children = self.children(id);
+        for (index, (name, child)) in children.iter().enumerate() {
+            let last = index + 1 == children.len();
+            let (branch, more) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            match *child {
+                Child::Directory(child) => {
+                    writeln!(text, "{indent}{branch}{name}/ ({})", sizes[child.index()]).unwrap();
+                    self.write_tree(text, child, &format!("{indent}{more}"), sizes);
+                }
+                Child::File(size) => writeln!(text, "{indent}{branch}{name} ({size})").unwrap(),
+            }
+        }
+    }
+
+    /// Every entry as `size<TAB>path`, like `du -a`, largest first.
+    pub fn to_du(&self) -> String {
+        let mut entries = self.entries();
+        entries.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+        entries
+            .iter()
+            .map(|entry| format!("{}\t{}\n", entry.size, entry.path))
+            .collect()
+    }
+
+    /// The whole hierarchy from `/` down.
+    pub fn to_node(&self) -> Node {
+        self.node(FileSystem::ROOT, &self.sizes())
+    }
+
+    fn node(&self, id: DirId, sizes: &[u64]) -> Node {
+        Node {
+            name: self.directory(id).name.clone(),
+            kind: EntryKind::Directory,
+            size: sizes[id.index()],
+            children: self
+                .children(id)
+                .into_iter()
+                .map(|(name, child)| match child {
+                    Child::Directory(child) => self.node(child, sizes),
+                    Child::File(size) => Node {
+                        name: name.to_string(),
+                        kind: EntryKind::File,
+                        size,
+                        children: Vec::new(),
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.to_node()).expect("file systems are plain data")
+    }
+
+    /// The entries matching `query`, in the order of [`entries`].
+    ///
+    /// [`entries`]: FileSystem::entries
+    pub fn find(&self, query: &Query) -> Vec<Entry> {
+        self.entries()
+            .into_iter()
+            .filter(|entry| {
+                let name = entry.path.rsplit('/').next().unwrap_or_default();
+                let name = if name.is_empty() { "/" } else { name };
+                query.matches(name, entry.size)
+            })
+            .collect()
+    }
+}
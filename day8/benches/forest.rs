@@ -0,0 +1,32 @@
+//! Times both parts on the prod forest two ways: copying each tree's four
+//! lines of sight and scanning them, against the four monotonic-stack
+//! sweeps of `Forest::survey`. The parse is done once up front, so only the
+//! surveys are measured. Run with
+//! `cargo bench -p day8 --features bench --bench forest`.
+
+use aoc_common::input_path;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day8::Forest;
+use std::fs;
+
+fn bench_forest(c: &mut Criterion) {
+    let input = fs::read_to_string(input_path(8, "prod.txt")).expect("day 8 has a prod input");
+    let forest: Forest = input.parse().expect("the prod forest parses");
+    let mut group = c.benchmark_group("day08-forest");
+    group.bench_with_input(BenchmarkId::new("part1", "copies"), &forest, |b, forest| {
+        b.iter(|| day8::naive::part_1(forest))
+    });
+    group.bench_with_input(BenchmarkId::new("part1", "sweeps"), &forest, |b, forest| {
+        b.iter(|| forest.survey().visible_count())
+    });
+    group.bench_with_input(BenchmarkId::new("part2", "copies"), &forest, |b, forest| {
+        b.iter(|| day8::naive::part_2(forest))
+    });
+    group.bench_with_input(BenchmarkId::new("part2", "sweeps"), &forest, |b, forest| {
+        b.iter(|| forest.survey().best_scenic_score())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_forest);
+criterion_main!(benches);
@@ -1,3 +1,5 @@
+use aoc_common::{Grid, ParseError, RaggedGridError};
+use std::fmt;
 use std::str::FromStr;
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Tree {
@@ -6,42 +8,56 @@ pub struct Tree {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Forest {
-    pub trees: Vec<Vec<Tree>>,
+    pub trees: Grid<Tree>,
 }
 
 #[derive(PartialEq, Clone, Copy, Debug, Eq)]
 pub enum ForestParseError {
     NonDigitCharacter,
+    RaggedRows,
 }
 
-impl FromStr for Forest {
-    type Err = ForestParseError;
+impl fmt::Display for ForestParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForestParseError::NonDigitCharacter => {
+                write!(f, "forest contains a non-digit character")
+            }
+            ForestParseError::RaggedRows => write!(f, "forest rows are not all the same width"),
+        }
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let trees = s.lines().map(parse_forest_line).collect::<Result<_, _>>()?;
-        Ok(Forest::new(trees))
+impl std::error::Error for ForestParseError {}
+
+impl From<RaggedGridError> for ForestParseError {
+    fn from(_: RaggedGridError) -> Self {
+        ForestParseError::RaggedRows
     }
 }
 
-fn parse_forest_line(line: &str) -> Result<Vec<Tree>, ForestParseError> {
-    line.chars()
-        .map(|char| {
+impl FromStr for Forest {
+    type Err = ParseError<ForestParseError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trees = Grid::try_parse(s, |char| -> Result<Tree, ForestParseError> {
             let height = char
                 .to_digit(10)
                 .ok_or(ForestParseError::NonDigitCharacter)?;
             Ok(Tree { height })
-        })
-        .collect()
+        })?;
+        Ok(Forest::new(trees))
+    }
 }
 
 impl Forest {
     pub fn width(&self) -> usize {
-        self.trees[0].len()
+        self.trees.width()
     }
     pub fn height(&self) -> usize {
-        self.trees.len()
+        self.trees.height()
     }
-    pub fn new(trees: Vec<Vec<Tree>>) -> Self {
+    pub fn new(trees: Grid<Tree>) -> Self {
         Forest { trees }
     }
 }
@@ -0,0 +1,280 @@
+#![deny(clippy::unwrap_used)]
+use anyhow::Result;
+use aoc_common::{Diagnostic, ParseError, Solution};
+use std::str::FromStr;
+use tracing::debug;
+mod forest;
+#[cfg(any(test, feature = "bench"))]
+pub mod naive;
+mod render;
+mod survey;
+pub use forest::*;
+pub use render::*;
+pub use survey::*;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError<ForestParseError>> {
+    let forest = Forest::from_str(input)?;
+
+    debug!(
+        width = forest.width(),
+        height = forest.height(),
+        "parsed forest"
+    );
+    Ok(forest.survey().visible_count())
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError<ForestParseError>> {
+    let forest = Forest::from_str(input)?;
+
+    debug!(
+        width = forest.width(),
+        height = forest.height(),
+        "parsed forest"
+    );
+
+    Ok(forest.survey().best_scenic_score())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::naive::{is_tree_visible, tree_score};
+    use crate::*;
+    use aoc_common::{Grid, Point};
+
+    #[test]
+    fn part_1_given() {
+        let input = include_str!("../../inputs/day08/dev.txt");
+
+        let tree_count = part_1(input).expect("the forest is valid");
+
+        assert_eq!(tree_count, 21);
+    }
+
+    #[test]
+    fn parse_forest_simple() {
+        let sample_forest_str = "123\n456";
+        let expected_forest = Forest::new(grid(vec![
+            vec![Tree { height: 1 }, Tree { height: 2 }, Tree { height: 3 }],
+            vec![Tree { height: 4 }, Tree { height: 5 }, Tree { height: 6 }],
+        ]));
+
+        let forest = Forest::from_str(sample_forest_str).expect("test forests are valid");
+
+        assert!(two_forests_equal(forest, expected_forest));
+    }
+
+    #[test]
+    fn parse_forest_reports_bad_tree() {
+        let error = Forest::from_str("123\n4a6").expect_err("`a` is not a height");
+
+        assert_eq!(
+            error,
+            ParseError::new(ForestParseError::NonDigitCharacter, 2, 2, "a")
+        );
+    }
+
+    fn two_forests_equal(first: Forest, second: Forest) -> bool {
+        first
+            .trees
+            .rows()
+            .zip(second.trees.rows())
+            .all(|(first_row, second_row)| {
+                first_row
+                    .iter()
+                    .all(|first_cell| second_row.contains(first_cell))
+            })
+    }
+
+    fn grid(rows: Vec<Vec<Tree>>) -> Grid<Tree> {
+        Grid::from_rows(rows).expect("test rows are all the same width")
+    }
+
+    #[test]
+    fn tree_edge_visible() {
+        let forest = Forest::new(grid(vec![
+            vec![Tree { height: 1 }, Tree { height: 2 }, Tree { height: 3 }],
+            vec![Tree { height: 4 }, Tree { height: 5 }, Tree { height: 6 }],
+        ]));
+
+        let tree_visibility = is_tree_visible(&forest, 0, 1);
+
+        assert!(tree_visibility);
+    }
+    #[test]
+    fn tree_is_visible() {
+        let forest = Forest::new(grid(vec![
+            vec![Tree { height: 1 }, Tree { height: 2 }, Tree { height: 3 }],
+            vec![Tree { height: 4 }, Tree { height: 10 }, Tree { height: 6 }],
+            vec![Tree { height: 4 }, Tree { height: 5 }, Tree { height: 1 }],
+        ]));
+
+        let tree_visibility = is_tree_visible(&forest, 1, 1);
+
+        assert!(tree_visibility);
+    }
+    #[test]
+    fn tree_hidden() {
+        let forest = Forest::new(grid(vec![
+            vec![Tree { height: 1 }, Tree { height: 2 }, Tree { height: 3 }],
+            vec![Tree { height: 4 }, Tree { height: 0 }, Tree { height: 6 }],
+            vec![Tree { height: 4 }, Tree { height: 5 }, Tree { height: 1 }],
+        ]));
+
+        let tree_visibility = is_tree_visible(&forest, 1, 1);
+
+        assert!(!tree_visibility);
+    }
+    #[test]
+    fn tree_score_basic() {
+        let forest = Forest::new(grid(vec![
+            vec![Tree { height: 1 }, Tree { height: 2 }, Tree { height: 3 }],
+            vec![Tree { height: 4 }, Tree { height: 7 }, Tree { height: 6 }],
+            vec![Tree { height: 4 }, Tree { height: 5 }, Tree { height: 1 }],
+        ]));
+
+        let tree_score = tree_score(&forest, 1, 1);
+
+        assert_eq!(tree_score, 1);
+    }
+    #[test]
+    fn tree_score_longer() {
+        let forest = Forest::new(grid(vec![
+            vec![Tree { height: 1 }, Tree { height: 2 }, Tree { height: 3 }],
+            vec![Tree { height: 10 }, Tree { height: 7 }, Tree { height: 6 }],
+            vec![Tree { height: 4 }, Tree { height: 5 }, Tree { height: 1 }],
+            vec![Tree { height: 4 }, Tree { height: 10 }, Tree { height: 1 }],
+        ]));
+
+        let tree_score = tree_score(&forest, 1, 1);
+
+        assert_eq!(tree_score, 2);
+    }
+    #[test]
+    fn part_2_given() {
+        let input = include_str!("../../inputs/day08/dev.txt");
+
+        let max_count = part_2(input).expect("the forest is valid");
+
+        assert_eq!(max_count, 8);
+    }
+
+    #[test]
+    fn part_2_full() {
+        let input = include_str!("../../inputs/day08/prod.txt");
+
+        let max_count = part_2(input).expect("the forest is valid");
+
+        assert_eq!(max_count, 345744);
+    }
+
+    #[test]
+    fn survey_matches_naive() {
+        for input in [
+            include_str!("../../inputs/day08/dev.txt"),
+            include_str!("../../inputs/day08/prod.txt"),
+        ] {
+            let forest = Forest::from_str(input).expect("test forests are valid");
+
+            let survey = forest.survey();
+
+            for x in 0..forest.height() {
+                for y in 0..forest.width() {
+                    let point = Point::new(y as i64, x as i64);
+                    assert_eq!(survey.visible[point], is_tree_visible(&forest, x, y));
+                    assert_eq!(survey.scenic_scores[point], tree_score(&forest, x, y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn survey_of_an_empty_forest() {
+        let survey = Forest::new(grid(Vec::new())).survey();
+
+        assert_eq!(survey.visible_count(), 0);
+        assert_eq!(survey.best_scenic_score(), 0);
+    }
+
+    #[test]
+    fn best_treehouse_given() {
+        let forest = Forest::from_str(include_str!("../../inputs/day08/dev.txt"))
+            .expect("test forests are valid");
+        let survey = forest.survey();
+
+        let house = survey.best_treehouse().expect("the forest has trees");
+        let seen = forest.sight_lines(house).map(|line| line.len());
+
+        assert_eq!(house, Point::new(2, 3));
+        assert_eq!(seen, [2, 2, 1, 2]);
+        assert_eq!(seen.iter().product::<usize>(), survey.best_scenic_score());
+    }
+
+    #[test]
+    fn heatmap_overlays() {
+        let forest = Forest::from_str("303\n393\n303").expect("test forests are valid");
+        let survey = forest.survey();
+        let plain = forest.heatmap(&survey, Overlay::default());
+        let overlaid = forest.heatmap(
+            &survey,
+            Overlay {
+                visibility: true,
+                treehouse: true,
+            },
+        );
+        let strip = |text: &str| {
+            let mut plain = String::new();
+            let mut escape = false;
+            for c in text.chars() {
+                match c {
+                    '\x1b' => escape = true,
+                    'm' if escape => escape = false,
+                    _ if !escape => plain.push(c),
+                    _ => {}
+                }
+            }
+            plain
+        };
+
+        assert_eq!(strip(&plain), "303\n393\n303\n");
+        assert_eq!(strip(&overlaid), strip(&plain));
+        // The middle tree is the treehouse; the one above it is on a sight line.
+        let middle = overlaid.lines().nth(1).expect("the heatmap has three rows");
+        assert!(middle.contains("\x1b[48;2;220;30;30m\x1b[1;38;2;255;255;255m9"));
+        assert!(overlaid
+            .lines()
+            .next()
+            .expect("the heatmap has three rows")
+            .contains("\x1b[48;2;70;130;230m"));
+    }
+
+    #[test]
+    fn images_given() {
+        let forest = Forest::from_str(include_str!("../../inputs/day08/dev.txt"))
+            .expect("test forests are valid");
+        let survey = forest.survey();
+
+        let heights = forest.levels(&survey, Layer::Heights);
+        let scores = forest.levels(&survey, Layer::ScenicScores);
+        let pgm = to_pgm(&heights);
+        let ppm = to_ppm(&scores);
+
+        assert!(pgm.starts_with(b"P5\n5 5\n255\n"));
+        assert_eq!(pgm.len(), "P5\n5 5\n255\n".len() + 25);
+        assert_eq!(pgm.last(), Some(&0));
+        assert!(ppm.starts_with(b"P6\n5 5\n255\n"));
+        assert_eq!(ppm.len(), "P6\n5 5\n255\n".len() + 75);
+        assert_eq!(scores[Point::new(2, 3)], 255);
+        assert_eq!(scores[Point::new(0, 0)], 0);
+    }
+}
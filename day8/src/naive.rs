@@ -0,0 +1,88 @@
+//! Per-tree visibility and scenic scores, as the crate first worked them
+//! out: every tree copies its row and column out of the grid on either side
+//! and scans them, which is cubic in the forest's side. The tests check
+//! [`Forest::survey`] against these, and the `forest` bench times the two.
+//! Only built for tests and with the `bench` feature.
+
+use crate::{Forest, Tree};
+use aoc_common::Point;
+
+pub fn is_tree_visible(forest: &Forest, x: usize, y: usize) -> bool {
+    let target_tree = forest.trees[Point::new(y as i64, x as i64)];
+
+    let tree_slices = TreeVisiblitySlices::create_for_tree(forest, x, y);
+
+    tree_visible_in_slice(&tree_slices.left, &target_tree)
+        || tree_visible_in_slice(&tree_slices.right, &target_tree)
+        || tree_visible_in_slice(&tree_slices.up, &target_tree)
+        || tree_visible_in_slice(&tree_slices.down, &target_tree)
+}
+
+#[derive(Debug)]
+struct TreeVisiblitySlices {
+    left: Vec<Tree>,
+    right: Vec<Tree>,
+    up: Vec<Tree>,
+    down: Vec<Tree>,
+}
+
+impl TreeVisiblitySlices {
+    fn create_for_tree(forest: &Forest, x: usize, y: usize) -> TreeVisiblitySlices {
+        let row = forest.trees.row(x);
+        let left_of_tree: Vec<Tree> = row[..y].to_vec();
+        let right_of_tree: Vec<Tree> = row[y + 1..].to_vec();
+        let above_tree: Vec<Tree> = forest.trees.column(y).take(x).copied().collect();
+        let below_tree: Vec<Tree> = forest.trees.column(y).skip(x + 1).copied().collect();
+        TreeVisiblitySlices {
+            left: left_of_tree,
+            right: right_of_tree,
+            up: above_tree,
+            down: below_tree,
+        }
+    }
+}
+
+fn tree_visible_in_slice(slice: &[Tree], target_tree: &Tree) -> bool {
+    slice.iter().all(|tree| tree.height < target_tree.height)
+}
+
+pub fn tree_score(forest: &Forest, x: usize, y: usize) -> usize {
+    let target_tree = forest.trees[Point::new(y as i64, x as i64)];
+
+    let mut tree_slices = TreeVisiblitySlices::create_for_tree(forest, x, y);
+
+    tree_slices.left.reverse();
+    tree_slices.up.reverse();
+
+    trees_visible_in_line(&tree_slices.left, &target_tree)
+        * trees_visible_in_line(&tree_slices.right, &target_tree)
+        * trees_visible_in_line(&tree_slices.up, &target_tree)
+        * trees_visible_in_line(&tree_slices.down, &target_tree)
+}
+
+fn trees_visible_in_line(line: &[Tree], target_tree: &Tree) -> usize {
+    match line
+        .iter()
+        .position(|tree| tree.height >= target_tree.height)
+    {
+        Some(tree_index) => tree_index + 1,
+        None => line.len(),
+    }
+}
+
+pub fn part_1(forest: &Forest) -> usize {
+    (0..forest.height())
+        .map(|x| {
+            (0..forest.width())
+                .filter(|&y| is_tree_visible(forest, x, y))
+                .count()
+        })
+        .sum()
+}
+
+pub fn part_2(forest: &Forest) -> usize {
+    (0..forest.height())
+        .flat_map(|x| (0..forest.width()).map(move |y| tree_score(forest, x, y)))
+        .max()
+        .unwrap_or(0)
+}
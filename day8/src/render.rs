@@ -0,0 +1,130 @@
+use crate::{Forest, Survey};
+use aoc_common::{Direction, Grid, Point};
+use std::collections::HashSet;
+
+/// What to draw over a heatmap.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Overlay {
+    /// Show trees visible from outside in bold white and the rest dimmed.
+    pub visibility: bool,
+    /// Highlight the best treehouse and the trees it can see.
+    pub treehouse: bool,
+}
+
+/// Which grid an image shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layer {
+    Heights,
+    ScenicScores,
+}
+
+const TREEHOUSE: [u8; 3] = [220, 30, 30];
+const SIGHT_LINE: [u8; 3] = [70, 130, 230];
+
+impl Survey {
+    /// The tree with the best scenic score, the first in reading order if
+    /// several tie.
+    pub fn best_treehouse(&self) -> Option<Point> {
+        let best = self.best_scenic_score();
+        self.scenic_scores.position(|&score| score == best)
+    }
+}
+
+impl Forest {
+    /// The trees a house at `from` would see, nearest first in each
+    /// direction, up to and including the one that blocks the view.
+    pub fn sight_lines(&self, from: Point) -> [Vec<Point>; 4] {
+        let height = self.trees[from].height;
+        Direction::ALL.map(|direction| {
+            let mut seen = Vec::new();
+            let mut point = from.step(direction);
+            while let Some(tree) = self.trees.get(point) {
+                seen.push(point);
+                if tree.height >= height {
+                    break;
+                }
+                point = point.step(direction);
+            }
+            seen
+        })
+    }
+
+    /// The forest as rows of digits on a background shaded by height, for a
+    /// terminal that understands 24-bit ANSI colours.
+    pub fn heatmap(&self, survey: &Survey, overlay: Overlay) -> String {
+        let treehouse = survey.best_treehouse().filter(|_| overlay.treehouse);
+        let sight_lines: HashSet<Point> = treehouse
+            .map(|house| self.sight_lines(house).into_iter().flatten().collect())
+            .unwrap_or_default();
+        let levels = self.levels(survey, Layer::Heights);
+
+        let mut text = String::new();
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let point = Point::new(x as i64, y as i64);
+                let [r, g, b] = if treehouse == Some(point) {
+                    TREEHOUSE
+                } else if sight_lines.contains(&point) {
+                    SIGHT_LINE
+                } else {
+                    heat(levels[point])
+                };
+                let foreground = match (overlay.visibility, survey.visible[point]) {
+                    (false, _) => "\x1b[38;2;0;0;0m",
+                    (true, true) => "\x1b[1;38;2;255;255;255m",
+                    (true, false) => "\x1b[2;38;2;60;60;60m",
+                };
+                let height = self.trees[point].height;
+                text.push_str(&format!(
+                    "\x1b[48;2;{r};{g};{b}m{foreground}{height}\x1b[0m"
+                ));
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// A layer scaled to 0..=255. Scenic scores are scaled logarithmically,
+    /// since a few trees score far higher than the rest.
+    pub fn levels(&self, survey: &Survey, layer: Layer) -> Grid<u8> {
+        let mut levels = Grid::new(self.width(), self.height(), 0);
+        let best = survey.best_scenic_score();
+        for (point, tree) in self.trees.iter() {
+            levels[point] = match layer {
+                Layer::Heights => (tree.height.min(9) * 255 / 9) as u8,
+                Layer::ScenicScores if best == 0 => 0,
+                Layer::ScenicScores => {
+                    let score = survey.scenic_scores[point] as f64;
+                    (score.ln_1p() / (best as f64).ln_1p() * 255.0).round() as u8
+                }
+            };
+        }
+        levels
+    }
+}
+
+/// A dark-green to pale-yellow ramp.
+fn heat(level: u8) -> [u8; 3] {
+    let level = u32::from(level);
+    let mix = |from: u32, to: u32, t: u32| (from + (to - from) * t / 127) as u8;
+    if level < 128 {
+        [mix(0, 40, level), mix(30, 160, level), mix(0, 40, level)]
+    } else {
+        let t = level - 128;
+        [mix(40, 250, t), mix(160, 240, t), mix(40, 160, t)]
+    }
+}
+
+/// Levels as a binary greyscale PGM image.
+pub fn to_pgm(levels: &Grid<u8>) -> Vec<u8> {
+    let mut image = format!("P5\n{} {}\n255\n", levels.width(), levels.height()).into_bytes();
+    image.extend(levels.iter().map(|(_, &level)| level));
+    image
+}
+
+/// Levels as a binary PPM image, coloured with the same ramp as the heatmap.
+pub fn to_ppm(levels: &Grid<u8>) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", levels.width(), levels.height()).into_bytes();
+    image.extend(levels.iter().flat_map(|(_, &level)| heat(level)));
+    image
+}
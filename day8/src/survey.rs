@@ -0,0 +1,85 @@
+use crate::Forest;
+use aoc_common::{Grid, Point};
+
+/// What can be seen from and of every tree in a forest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Survey {
+    /// Whether the tree can be seen from outside the forest.
+    pub visible: Grid<bool>,
+    /// How far the tree's house would see in each direction, multiplied.
+    pub scenic_scores: Grid<usize>,
+}
+
+impl Survey {
+    pub fn visible_count(&self) -> usize {
+        self.visible.iter().filter(|(_, &visible)| visible).count()
+    }
+
+    pub fn best_scenic_score(&self) -> usize {
+        self.scenic_scores
+            .iter()
+            .map(|(_, &score)| score)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Forest {
+    /// Surveys every tree with one sweep along each row and column in each
+    /// direction, so the whole forest costs time linear in its size.
+    pub fn survey(&self) -> Survey {
+        let (width, height) = (self.width(), self.height());
+        let mut survey = Survey {
+            visible: Grid::new(width, height, false),
+            scenic_scores: Grid::new(width, height, 1),
+        };
+        let mut stack = Vec::with_capacity(width.max(height));
+        let point = |x: usize, y: usize| Point::new(x as i64, y as i64);
+        for y in 0..height {
+            self.sweep((0..width).map(|x| point(x, y)), &mut stack, &mut survey);
+            self.sweep(
+                (0..width).rev().map(|x| point(x, y)),
+                &mut stack,
+                &mut survey,
+            );
+        }
+        for x in 0..width {
+            self.sweep((0..height).map(|y| point(x, y)), &mut stack, &mut survey);
+            self.sweep(
+                (0..height).rev().map(|y| point(x, y)),
+                &mut stack,
+                &mut survey,
+            );
+        }
+        survey
+    }
+
+    /// Looks back along one line from each tree on it. `stack` holds the
+    /// trees not yet overshadowed by a later one, tallest at the bottom, so
+    /// after popping everything shorter than a tree, the top of the stack is
+    /// the nearest tree that blocks its view, and an empty stack means it can
+    /// be seen from the edge.
+    fn sweep(
+        &self,
+        line: impl Iterator<Item = Point>,
+        stack: &mut Vec<(u32, usize)>,
+        survey: &mut Survey,
+    ) {
+        stack.clear();
+        for (distance, point) in line.enumerate() {
+            let height = self.trees[point].height;
+            while stack.last().is_some_and(|&(blocker, _)| blocker < height) {
+                stack.pop();
+            }
+            let seen = match stack.last() {
+                Some(&(_, blocker)) => distance - blocker,
+                None => {
+                    survey.visible[point] = true;
+                    distance
+                }
+            };
+            survey.scenic_scores[point] *= seen;
+            stack.push((height, distance));
+        }
+    }
+}
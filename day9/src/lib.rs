@@ -0,0 +1,226 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::{parse_lines, Diagnostic, Direction, ParseError, Point, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(part_1(input).map_err(Diagnostic::from)?.to_string())
+    }
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(part_2(input).map_err(Diagnostic::from)?.to_string())
+    }
+}
+
+#[derive(Debug)]
+struct DirectionMove {
+    direction: Direction,
+    amount: i64,
+}
+
+#[derive(Debug)]
+struct RelativeMove {
+    #[allow(dead_code)]
+    from: Point,
+    to: Point,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MotionError {
+    MissingAmount,
+    NotADirection,
+    NotANumber,
+}
+
+impl fmt::Display for MotionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MotionError::MissingAmount => write!(f, "expected a direction and a number of steps"),
+            MotionError::NotADirection => write!(f, "direction should be one of U, D, L or R"),
+            MotionError::NotANumber => write!(f, "number of steps is not a number"),
+        }
+    }
+}
+
+impl FromStr for DirectionMove {
+    type Err = ParseError<MotionError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (d, a) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(MotionError::MissingAmount, s, s))?;
+        let mut chars = d.chars();
+        let direction = match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| ParseError::at(MotionError::NotADirection, s, d))?;
+        let amount = a
+            .parse()
+            .map_err(|_| ParseError::at(MotionError::NotANumber, s, a))?;
+
+        Ok(Self { direction, amount })
+    }
+}
+
+#[derive(Debug)]
+struct Knot {
+    position: Point,
+    history: Vec<Point>,
+}
+
+impl Knot {
+    fn new() -> Self {
+        Self {
+            position: Point::ORIGIN,
+            history: vec![Point::ORIGIN],
+        }
+    }
+    fn move_directly(&mut self, direct_move: DirectionMove) -> Vec<RelativeMove> {
+        let move_history: Vec<RelativeMove> = (0..direct_move.amount)
+            .map(|_| {
+                let initial_position = self.position;
+                self.move_single_step(&direct_move.direction);
+                let final_position = self.position;
+                RelativeMove {
+                    from: initial_position,
+                    to: final_position,
+                }
+            })
+            .collect();
+
+        move_history
+    }
+
+    fn move_relatively(&mut self, relative_moves: &[RelativeMove]) -> Vec<RelativeMove> {
+        let propogated_moves = relative_moves
+            .iter()
+            .flat_map(|relative_move| {
+                let initial_position = self.position;
+                let final_relative_position = relative_move.to - self.position;
+                if final_relative_position.x.abs() < 2 && final_relative_position.y.abs() < 2 {
+                    return None;
+                } else if final_relative_position.x.abs() == 2
+                    && final_relative_position.y.abs() == 2
+                {
+                    self.position.x += final_relative_position.x / 2;
+                    self.position.y += final_relative_position.y / 2;
+                } else if final_relative_position.x.abs() == 2 {
+                    self.position.x += final_relative_position.x / 2;
+                    self.position.y = relative_move.to.y;
+                } else if final_relative_position.y.abs() == 2 {
+                    self.position.y += final_relative_position.y / 2;
+                    self.position.x = relative_move.to.x;
+                } else {
+                    unreachable!();
+                }
+                let move_done = RelativeMove {
+                    from: initial_position,
+                    to: self.position,
+                };
+                self.history.push(move_done.to);
+
+                Some(move_done)
+            })
+            .collect();
+
+        propogated_moves
+    }
+
+    fn move_single_step(&mut self, direction: &Direction) -> RelativeMove {
+        let initial_position = self.position;
+        self.position = self.position.step(*direction);
+        let final_position = self.position;
+        RelativeMove {
+            from: initial_position,
+            to: final_position,
+        }
+    }
+}
+
+struct Rope {
+    head: Knot,
+    following_knots: VecDeque<Knot>,
+}
+
+impl Rope {
+    fn new(length: usize) -> Self {
+        let mut following_knots = VecDeque::new();
+        for _ in 0..length - 1 {
+            following_knots.push_back(Knot::new())
+        }
+        Self {
+            head: Knot::new(),
+            following_knots,
+        }
+    }
+}
+
+impl Rope {
+    fn tail(&self) -> &Knot {
+        self.following_knots.back().unwrap()
+    }
+
+    fn count_tail_positions(&self) -> usize {
+        HashSet::<_>::from_iter(self.tail().history.iter()).len()
+    }
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError<MotionError>> {
+    count_tail_positions(input, 2)
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError<MotionError>> {
+    count_tail_positions(input, 10)
+}
+
+fn count_tail_positions(input: &str, rope_length: usize) -> Result<usize, ParseError<MotionError>> {
+    let mut rope = Rope::new(rope_length);
+    let moves: Vec<DirectionMove> = parse_lines(input)?;
+
+    for head_move in moves {
+        let mut relative_moves = rope.head.move_directly(head_move);
+        for following_knot in rope.following_knots.iter_mut() {
+            relative_moves = following_knot.move_relatively(&relative_moves);
+        }
+    }
+
+    Ok(rope.count_tail_positions())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn given_test() {
+        let input = include_str!("../../inputs/day09/dev.txt");
+
+        let result = part_1(input).unwrap();
+
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn part_2_test() {
+        let input = include_str!("../../inputs/day09/dev_2.txt");
+
+        let result = part_2(input).unwrap();
+
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn bad_direction_is_located() {
+        let error = part_1("R 4\nU 4\nX 3").unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(MotionError::NotADirection, 3, 1, "X")
+        );
+    }
+}